@@ -0,0 +1,56 @@
+import 'dart:typed_data';
+
+import 'package:integration_test/integration_test.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:demo_rust_native_plugin/demo_rust_native_plugin.dart';
+
+/// Round-trip benchmark for large byte payloads.
+///
+/// Run with `flutter test integration_test/buffer_benchmark_test.dart` and
+/// compare the `Vec<u8>` and `NativeBuffer` timings printed for each size.
+void main() {
+  IntegrationTestWidgetsFlutterBinding.ensureInitialized();
+  setUpAll(() async => await RustLib.init());
+
+  const iterations = 10;
+
+  Future<Duration> median(Future<void> Function() body) async {
+    final samples = <Duration>[];
+    for (var i = 0; i < iterations; i++) {
+      final watch = Stopwatch()..start();
+      await body();
+      samples.add(watch.elapsed);
+    }
+    samples.sort();
+    return samples[iterations ~/ 2];
+  }
+
+  for (final size in [64 * 1024, 1024 * 1024, 16 * 1024 * 1024]) {
+    test('Round trip of $size bytes', () async {
+      final data = Uint8List(size);
+      for (var i = 0; i < size; i++) {
+        data[i] = i;
+      }
+
+      late Uint8List viaList;
+      final listTime = await median(() async {
+        viaList = await xorBytes(data: data, key: 0x5a);
+      });
+
+      final buffer = await NativeBuffer.newInstance(len: BigInt.from(size));
+      final view = buffer.asUint8List();
+      final bufferTime = await median(() async {
+        view.setAll(0, data);
+        await xorBuffer(buffer: buffer, key: 0x5a);
+      });
+
+      expect(view, viaList);
+      // ignore: avoid_print
+      print(
+        '$size bytes: Vec<u8> ${listTime.inMicroseconds} us, '
+        'NativeBuffer ${bufferTime.inMicroseconds} us',
+      );
+      buffer.dispose();
+    });
+  }
+}
@@ -1,11 +1,272 @@
+import 'dart:convert';
+import 'dart:typed_data';
+
 import 'package:integration_test/integration_test.dart';
 import 'package:flutter_test/flutter_test.dart';
 import 'package:demo_rust_native_plugin/demo_rust_native_plugin.dart';
 
 void main() {
   IntegrationTestWidgetsFlutterBinding.ensureInitialized();
-  setUpAll(() async => await RustLib.init());
+  setUpAll(() async => await initNativePlugin());
   test('Can call rust function', () async {
     expect(greet(name: "Tom"), "Hello, Tom!");
   });
+  test('Greetings are localized with fallback', () async {
+    expect(
+      await greet(name: 'Ana', locale: 'pt-BR', gender: Gender.feminine),
+      'Oi, Ana, bem-vinda! 🦀',
+    );
+    expect(await greet(name: 'Ana', locale: 'pt-PT'), 'Olá, Ana! 🦀');
+    expect(await greet(name: 'Tom', locale: 'xx'), 'Hello, Tom! 🦀');
+    final arabic = negotiateLocale(requested: 'ar-EG');
+    expect(arabic.fallbacks, ['ar', 'en']);
+    expect(arabic.direction, WritingDirection.rtl);
+    expect(negotiateLocale(requested: 'pt-BR').fallbacks, ['pt-BR', 'pt', 'en']);
+  });
+  test('Translations can be added at runtime', () async {
+    await addTranslations(locale: 'sv', ftl: 'greeting = Hej { \$name }!');
+    expect(supportedLocales(), contains('sv'));
+    expect(await greet(name: 'Tom', locale: 'sv-SE'), 'Hej Tom! 🦀');
+    await expectLater(
+      addTranslations(locale: 'sv', ftl: 'greeting = {'),
+      throwsA(isA<PluginError_InvalidArgument>()),
+    );
+  });
+  test('Greetings can be rendered from templates', () async {
+    await registerGreetingTemplate(
+      name: 'formal',
+      source: '{{ salutation }} {{ name | title }}'
+          '{% if gender == "feminine" %}, madam{% endif %}',
+    );
+    expect(greetingTemplates(), contains('formal'));
+    expect(
+      await greet(name: 'ana lima', gender: Gender.feminine, template: 'formal'),
+      'Hello Ana Lima, madam 🦀',
+    );
+    await expectLater(
+      registerGreetingTemplate(name: 'broken', source: '{{ name | shout }}'),
+      throwsA(isA<PluginError_InvalidArgument>()),
+    );
+    expect(unregisterGreetingTemplate(name: 'formal'), isTrue);
+    await expectLater(
+      greet(name: 'Tom', template: 'formal'),
+      throwsA(isA<PluginError_InvalidArgument>()),
+    );
+  });
+  test('Names are normalized and checked for spoofing', () async {
+    final name = await processName(name: '  Eve\u202Elyn  ');
+    expect(name.name, 'Evelyn');
+    final removed = name.warnings.single as NameWarning_RemovedCharacters;
+    expect(removed.codePoints, [0x202E]);
+    final spoofed = await processName(name: '\u0410lice');
+    expect(spoofed.warnings.single, isA<NameWarning_MixedScript>());
+    expect(spoofed.scripts, ['Cyrillic', 'Latin']);
+    expect((await processName(name: '👨‍👩‍👧')).graphemes, 1);
+    await expectLater(
+      greet(name: '\u200B'),
+      throwsA(isA<PluginError_InvalidArgument>()),
+    );
+  });
+  test('Greeting style sets decoration and characters', () async {
+    expect(
+      await greet(
+        name: 'Tom',
+        locale: 'ja',
+        style: const GreetingStyle(
+          emoji: EmojiSet.party,
+          mode: TextMode.ascii,
+          prefix: null,
+          suffix: null,
+        ),
+      ),
+      r'konnitiha, Tomsan! \o/',
+    );
+    expect(
+      await greet(
+        name: 'Tom',
+        style: const GreetingStyle(
+          emoji: null,
+          mode: TextMode.screenReader,
+          prefix: '> ',
+          suffix: null,
+        ),
+      ),
+      '> Hello, Tom!',
+    );
+    expect(emojiSupport(emoji: '🦀', supportedVersion: null).likelyRenderable, isTrue);
+    final melting = emojiSupport(emoji: '🫠', supportedVersion: '13.1');
+    expect(melting.emojiVersion, '14.0');
+    expect(melting.likelyRenderable, isFalse);
+  });
+  test('Greetings follow the time of day in a time zone', () async {
+    // 16:30 UTC, hours after New York moved to daylight saving time
+    final now = DateTime.utc(2024, 3, 10, 16, 30).millisecondsSinceEpoch;
+    expect(
+      timeOfDay(timeZone: 'America/New_York', nowUtcMillis: now),
+      TimeOfDay.afternoon,
+    );
+    expect(
+      timeOfDay(timeZone: 'Asia/Tokyo', nowUtcMillis: now),
+      TimeOfDay.night,
+    );
+    expect(
+      await greet(
+        name: 'Tom',
+        locale: 'de',
+        timeZone: 'Asia/Tokyo',
+        nowUtcMillis: now,
+      ),
+      'Guten Abend, Tom! 🦀',
+    );
+    expect(
+      () => timeOfDay(timeZone: 'Mars/Olympus_Mons'),
+      throwsA(isA<PluginError_InvalidArgument>()),
+    );
+    expect(buildInfo().tzdbVersion, isNotEmpty);
+  });
+  test('Overflow is reported as a typed exception', () async {
+    await expectLater(
+      calculateFibonacci(n: 94),
+      throwsA(isA<PluginError_Overflow>()),
+    );
+  });
+  test('Overflow policies differ only on overflow', () async {
+    const max = 9223372036854775807;
+    expect(
+      await addNumbers(a: max, b: 1, policy: OverflowPolicy.saturating),
+      max,
+    );
+    await expectLater(
+      addNumbers(a: max, b: 1, policy: OverflowPolicy.checked),
+      throwsA(isA<PluginError_Overflow>()),
+    );
+  });
+  test('Log records are streamed to Dart', () async {
+    setLogTargetLevel(
+      target: 'demo_rust_native_plugin::api::simple',
+      level: LogLevel.debug,
+    );
+    final record = subscribeLogs().firstWhere((r) => r.message == 'greet');
+    // Let the subscription reach Rust before logging
+    await Future<void>.delayed(const Duration(milliseconds: 100));
+    await greet(name: "Tom");
+    final greetRecord = await record;
+    expect(greetRecord.level, LogLevel.debug);
+    expect(greetRecord.fields.map((f) => f.key), [
+      'name_len',
+      'locale',
+      'template',
+      'time_zone',
+    ]);
+    clearLogTargetLevels();
+  });
+  test('Fibonacci sequence is streamed in chunks', () async {
+    final chunks = await fibonacciSequence(
+      start: 0,
+      count: 10,
+      chunkSize: 4,
+      cancellation: CancellationToken(),
+    ).toList();
+    expect(chunks.map((c) => c.start), [0, 4, 8]);
+    expect(chunks.expand((c) => c.terms).last, '34');
+  });
+  test('Cancelled calls fail with PluginError.cancelled', () async {
+    final token = CancellationToken()..cancel();
+    await expectLater(
+      calculateFibonacciBig(
+        n: 1000000,
+        cancellation: token,
+        progress: ProgressReporter(minIntervalMs: 0),
+      ),
+      throwsA(isA<PluginError_Cancelled>()),
+    );
+  });
+  test('Progress is reported alongside the result', () async {
+    final reporter = ProgressReporter(minIntervalMs: 0);
+    final done = reporter.updates().firstWhere((u) => u.fraction == 1.0);
+    await Future<void>.delayed(const Duration(milliseconds: 100));
+    final value = await calculateFibonacciBig(
+      n: 100,
+      cancellation: CancellationToken(),
+      progress: reporter,
+    );
+    expect(value, '354224848179261915075');
+    expect((await done).message, isNull);
+  });
+  test('Sync variants return without a Future', () {
+    expect(addNumbersSync(a: 2, b: 3, policy: OverflowPolicy.checked), 5);
+    expect(calculateFibonacciSync(n: 20), BigInt.from(6765));
+    expect(greetSync(name: 'Tom', locale: 'de'), 'Hallo, Tom! 🦀');
+    expect(
+      () => greetSync(name: 'x' * 1025),
+      throwsA(isA<PluginError_InvalidArgument>()),
+    );
+  });
+  test('Batch arithmetic works on typed lists', () async {
+    final a = Int64List.fromList([1, 2, 3]);
+    final b = Int64List.fromList([4, 5, 6]);
+    expect(
+      await batchI64(
+        a: a,
+        b: b,
+        op: BatchOp.add,
+        policy: OverflowPolicy.checked,
+      ),
+      [5, 7, 9],
+    );
+    expect(
+      await dotProductI64(a: a, b: b, policy: OverflowPolicy.checked),
+      32,
+    );
+    expect(await prefixSumsF64(values: [1.0, 2.0, 3.5]), [1.0, 3.0, 6.5]);
+  });
+  test('Calls can set their own priority', () async {
+    final value = await withTaskPriority(
+      TaskPriority.interactive,
+      () => calculateFibonacciBig(
+        n: 100,
+        cancellation: CancellationToken(),
+        progress: ProgressReporter(minIntervalMs: 0),
+      ),
+    );
+    expect(value, '354224848179261915075');
+    expect(workerPoolMetrics().every((m) => m.queued == 0), isTrue);
+  });
+  test('Build info describes the loaded library', () {
+    final info = buildInfo();
+    expect(info.frbCodegenVersion, '2.11.1');
+    expect(info.profile, anyOf('debug', 'release'));
+    expect(info.gitCommit, isNotEmpty);
+  });
+  test('Native library reports the API it implements', () {
+    final surface = loadedNativeApiSurface!;
+    expect(surface.contentHash, buildInfo().frbContentHash);
+    expect(
+      surface.functions.map((f) => f.path),
+      contains('crate::api::simple::greet'),
+    );
+  });
+  test('Functions can be called by name with JSON arguments', () async {
+    final greet = listFunctions().firstWhere((f) => f.name == 'greet');
+    expect(
+      greet.params.map((p) => p.optional),
+      [false, true, true, true, true, true],
+    );
+    expect(
+      jsonDecode(await invoke(name: 'greet', argsJson: '{"name": "Tom"}')),
+      'Hello, Tom! 🦀',
+    );
+    await expectLater(
+      invoke(name: 'calculate_fibonacci', argsJson: '{"n": 94}'),
+      throwsA(isA<PluginError_Overflow>()),
+    );
+  });
+  test('API schema describes every function', () {
+    final schema = jsonDecode(apiSchema()) as Map<String, dynamic>;
+    expect(schema['content_hash'], buildInfo().frbContentHash);
+    final functions = schema['functions'] as List<dynamic>;
+    final greet = functions.firstWhere((f) => f['name'] == 'greet');
+    expect(greet['path'], 'crate::api::simple::greet');
+    expect(greet['returns'], {'type': 'string'});
+  });
 }
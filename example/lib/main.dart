@@ -1,15 +1,23 @@
 import 'package:flutter/material.dart';
 import 'package:demo_rust_native_plugin/demo_rust_native_plugin.dart';
 
-// Import the generated initialization
-import 'package:demo_rust_native_plugin/src/rust/frb_generated.dart';
-
 Future<void> main() async {
   // Ensure Flutter is initialized
   WidgetsFlutterBinding.ensureInitialized();
 
-  // Initialize Flutter Rust Bridge
-  await RustLib.init();
+  // Load the native library, check it matches the bindings, then initialize
+  // Flutter Rust Bridge
+  await initNativePlugin();
+
+  // Configure the Rust side (safe to call again later)
+  await initPlugin(
+    config: const PluginConfig(
+      logLevel: LogLevel.info,
+      workerThreads: null,
+      blockingThreads: null,
+      dataDirectory: null,
+    ),
+  );
 
   runApp(const MyApp());
 }
@@ -41,7 +49,11 @@ class _MyAppState extends State<MyApp> {
   }
 
   Future<void> _callAddition() async {
-    final result = await addNumbers(a: 42, b: 13);
+    final result = await addNumbers(
+      a: 42,
+      b: 13,
+      policy: OverflowPolicy.checked,
+    );
     setState(() {
       _addition = '42 + 13 = $result';
     });
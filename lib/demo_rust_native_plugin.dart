@@ -1,4 +1,22 @@
 library;
 
+export 'src/native_api.dart';
+export 'src/native_buffer_view_io.dart'
+    if (dart.library.js_interop) 'src/native_buffer_view_web.dart';
+export 'src/rust/api/batch.dart';
+export 'src/rust/api/buffer.dart';
+export 'src/rust/api/cancellation.dart';
+export 'src/rust/api/dispatch.dart';
+export 'src/rust/api/error.dart';
+export 'src/rust/api/info.dart';
+export 'src/rust/api/init.dart';
+export 'src/rust/api/locale.dart';
+export 'src/rust/api/logging.dart';
+export 'src/rust/api/progress.dart';
 export 'src/rust/api/simple.dart';
+export 'src/rust/api/style.dart';
+export 'src/rust/api/templates.dart';
+export 'src/rust/api/time.dart';
+export 'src/rust/api/workers.dart';
 export 'src/rust/frb_generated.dart' show RustLib;
+export 'src/task_priority.dart';
@@ -0,0 +1,138 @@
+import 'dart:convert';
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+import 'native_api_surface_io.dart'
+    if (dart.library.js_interop) 'native_api_surface_web.dart';
+import 'rust/frb_generated.dart';
+
+/// A function the native library's dispatchers accept.
+class NativeApiFunction {
+  final int funcId;
+
+  /// Rust path, e.g. `crate::api::simple::greet`.
+  final String path;
+  final String signature;
+  final bool sync;
+
+  const NativeApiFunction({
+    required this.funcId,
+    required this.path,
+    required this.signature,
+    required this.sync,
+  });
+
+  factory NativeApiFunction.fromJson(Map<String, dynamic> json) =>
+      NativeApiFunction(
+        funcId: json['func_id'] as int,
+        path: json['path'] as String,
+        signature: json['signature'] as String,
+        sync: json['sync'] as bool,
+      );
+
+  @override
+  String toString() => '$funcId: $signature${sync ? ' [sync]' : ''}';
+}
+
+/// The API and build a loaded native library reports about itself.
+class NativeApiSurface {
+  final int contentHash;
+  final String codegenVersion;
+  final String crateVersion;
+  final String gitCommit;
+  final String target;
+  final String profile;
+  final List<NativeApiFunction> functions;
+
+  const NativeApiSurface({
+    required this.contentHash,
+    required this.codegenVersion,
+    required this.crateVersion,
+    required this.gitCommit,
+    required this.target,
+    required this.profile,
+    required this.functions,
+  });
+
+  factory NativeApiSurface.fromJson(Map<String, dynamic> json) =>
+      NativeApiSurface(
+        contentHash: json['content_hash'] as int,
+        codegenVersion: json['codegen_version'] as String,
+        crateVersion: json['crate_version'] as String,
+        gitCommit: json['git_commit'] as String,
+        target: json['target'] as String,
+        profile: json['profile'] as String,
+        functions: [
+          for (final function in json['functions'] as List<dynamic>)
+            NativeApiFunction.fromJson(function as Map<String, dynamic>),
+        ],
+      );
+}
+
+/// Thrown when the native library was built from a different Rust API than
+/// the one these Dart bindings were generated for.
+class NativeApiMismatch implements Exception {
+  /// Content hash of the Dart bindings.
+  final int expectedContentHash;
+
+  /// What the library reports, or `null` if it predates the handshake.
+  final NativeApiSurface? native;
+
+  const NativeApiMismatch(this.expectedContentHash, this.native);
+
+  @override
+  String toString() {
+    final native = this.native;
+    final found = native == null
+        ? 'a library without the API handshake, so it predates these bindings'
+        : 'content hash ${native.contentHash} from demo_rust_native_plugin '
+              '${native.crateVersion} (commit ${native.gitCommit}, '
+              '${native.profile} build for ${native.target}, '
+              'flutter_rust_bridge ${native.codegenVersion}, '
+              '${native.functions.length} functions)';
+    return 'NativeApiMismatch: the Dart bindings expect content hash '
+        '$expectedContentHash but loaded $found.\n'
+        'If a stale local build was picked up, rebuild it with '
+        '`cd rust && cargo build --release`. If the Rust API changed, '
+        'regenerate the bindings with `flutter_rust_bridge_codegen generate`.';
+  }
+}
+
+/// Reads the API surface of [library], or `null` if it predates the
+/// handshake. Always `null` on the web.
+NativeApiSurface? nativeApiSurface(ExternalLibrary library) {
+  final json = nativeApiSurfaceJson(library);
+  if (json == null) return null;
+  return NativeApiSurface.fromJson(jsonDecode(json) as Map<String, dynamic>);
+}
+
+/// Surface of the library loaded by [initNativePlugin]; `null` on the web.
+NativeApiSurface? get loadedNativeApiSurface => _loaded;
+NativeApiSurface? _loaded;
+
+/// Returns the API surface of [library], throwing [NativeApiMismatch] unless
+/// it is the API these bindings were generated for. Returns `null` without
+/// checking on the web.
+NativeApiSurface? checkNativeApi(ExternalLibrary library) {
+  if (!canReadNativeApiSurface) return null;
+  // ignore: invalid_use_of_internal_member, invalid_use_of_protected_member
+  final expected = RustLib.instance.rustContentHash;
+  final native = nativeApiSurface(library);
+  if (native == null || native.contentHash != expected) {
+    throw NativeApiMismatch(expected, native);
+  }
+  return native;
+}
+
+/// Loads the native library, checks it with [checkNativeApi], then
+/// initializes [RustLib].
+///
+/// Use this instead of `RustLib.init()` so a mismatched library fails with a
+/// [NativeApiMismatch] before any call reaches it.
+Future<void> initNativePlugin({ExternalLibrary? externalLibrary}) async {
+  final library =
+      externalLibrary ??
+      await loadExternalLibrary(RustLib.kDefaultExternalLibraryLoaderConfig);
+  _loaded = checkNativeApi(library);
+  await RustLib.init(externalLibrary: library);
+}
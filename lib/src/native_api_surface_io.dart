@@ -0,0 +1,32 @@
+import 'dart:convert';
+import 'dart:ffi';
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+const canReadNativeApiSurface = true;
+
+/// JSON from `demo_rust_native_plugin_api_surface`, or `null` if the library
+/// does not export it.
+String? nativeApiSurfaceJson(ExternalLibrary library) {
+  final lib = library.ffiDynamicLibrary;
+  if (!lib.providesSymbol('demo_rust_native_plugin_api_surface')) return null;
+  final surface = lib
+      .lookupFunction<Pointer<Uint8> Function(), Pointer<Uint8> Function()>(
+        'demo_rust_native_plugin_api_surface',
+      );
+  final free = lib
+      .lookupFunction<
+        Void Function(Pointer<Uint8>),
+        void Function(Pointer<Uint8>)
+      >('demo_rust_native_plugin_free_string');
+  final text = surface();
+  try {
+    var length = 0;
+    while (text[length] != 0) {
+      length++;
+    }
+    return utf8.decode(text.asTypedList(length));
+  } finally {
+    free(text);
+  }
+}
@@ -0,0 +1,6 @@
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// The web build has no C entry points to ask.
+const canReadNativeApiSurface = false;
+
+String? nativeApiSurfaceJson(ExternalLibrary library) => null;
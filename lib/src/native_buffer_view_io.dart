@@ -0,0 +1,14 @@
+import 'dart:ffi';
+import 'dart:typed_data';
+
+import 'rust/api/buffer.dart';
+
+extension NativeBufferView on NativeBuffer {
+  /// Views the buffer's bytes in place, without copying.
+  ///
+  /// The view is only valid while this buffer is alive and not disposed.
+  /// Do not write to it while a Rust call is using the buffer.
+  Uint8List asUint8List() => Pointer<Uint8>.fromAddress(
+    address().toInt(),
+  ).asTypedList(len().toInt());
+}
@@ -0,0 +1,9 @@
+import 'dart:typed_data';
+
+import 'rust/api/buffer.dart';
+
+extension NativeBufferView on NativeBuffer {
+  /// Views the buffer's bytes in place; not available on the web.
+  Uint8List asUint8List() =>
+      throw UnsupportedError('NativeBuffer views require dart:ffi');
+}
@@ -0,0 +1,109 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'simple.dart';
+
+/// Apply `op` to each pair `a[i]`, `b[i]`
+///
+/// Integer results are computed exactly, then `policy` decides what happens to
+/// those that do not fit in `i64`. Division by zero fails under every policy.
+Future<Int64List> batchI64({
+  required Int64List a,
+  required Int64List b,
+  required BatchOp op,
+  required OverflowPolicy policy,
+}) => RustLib.instance.api.crateApiBatchBatchI64(
+  a: a,
+  b: b,
+  op: op,
+  policy: policy,
+);
+
+/// Apply `op` to each pair `a[i]`, `b[i]` with IEEE 754 semantics
+Future<Float64List> batchF64({
+  required List<double> a,
+  required List<double> b,
+  required BatchOp op,
+}) => RustLib.instance.api.crateApiBatchBatchF64(a: a, b: b, op: op);
+
+/// Sum of `a[i] * b[i]`
+Future<PlatformInt64> dotProductI64({
+  required Int64List a,
+  required Int64List b,
+  required OverflowPolicy policy,
+}) => RustLib.instance.api.crateApiBatchDotProductI64(
+  a: a,
+  b: b,
+  policy: policy,
+);
+
+/// Sum of `a[i] * b[i]`; parallel summation may round differently in the last bits
+Future<double> dotProductF64({
+  required List<double> a,
+  required List<double> b,
+}) => RustLib.instance.api.crateApiBatchDotProductF64(a: a, b: b);
+
+/// Running totals: element `i` is the sum of `values[0..=i]`
+Future<Int64List> prefixSumsI64({
+  required Int64List values,
+  required OverflowPolicy policy,
+}) => RustLib.instance.api.crateApiBatchPrefixSumsI64(
+  values: values,
+  policy: policy,
+);
+
+/// Running totals: element `i` is the sum of `values[0..=i]`
+Future<Float64List> prefixSumsF64({required List<double> values}) =>
+    RustLib.instance.api.crateApiBatchPrefixSumsF64(values: values);
+
+/// Fold `values` into one number
+///
+/// Sums and products are computed exactly before `policy` applies.
+Future<PlatformInt64> reduceI64({
+  required Int64List values,
+  required Reduction reduction,
+  required OverflowPolicy policy,
+}) => RustLib.instance.api.crateApiBatchReduceI64(
+  values: values,
+  reduction: reduction,
+  policy: policy,
+);
+
+/// Fold `values` into one number; parallel sums may round differently in the last bits
+///
+/// `Min` and `Max` ignore NaN unless every element is NaN.
+Future<double> reduceF64({
+  required List<double> values,
+  required Reduction reduction,
+}) => RustLib.instance.api.crateApiBatchReduceF64(
+  values: values,
+  reduction: reduction,
+);
+
+/// Element-wise operation applied by `batch_i64` and `batch_f64`
+enum BatchOp {
+  add,
+  subtract,
+  multiply,
+  /// Integer division rounds toward zero
+  divide,
+  ;
+}
+
+/// Reduction applied by `reduce_i64` and `reduce_f64`
+enum Reduction {
+  /// Zero for an empty list
+  sum,
+  /// One for an empty list
+  product,
+  /// Fails for an empty list
+  min,
+  /// Fails for an empty list
+  max,
+  ;
+}
@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Adler-32 checksum of `data`
+Future<int> checksumBytes({required List<int> data}) =>
+    RustLib.instance.api.crateApiBufferChecksumBytes(data: data);
+
+/// Adler-32 checksum of the buffer contents
+Future<int> checksumBuffer({required NativeBuffer buffer}) =>
+    RustLib.instance.api.crateApiBufferChecksumBuffer(buffer: buffer);
+
+/// XOR every byte with `key`, returning a new list
+Future<Uint8List> xorBytes({required List<int> data, required int key}) =>
+    RustLib.instance.api.crateApiBufferXorBytes(data: data, key: key);
+
+/// XOR every byte of the buffer with `key` in place
+Future<void> xorBuffer({required NativeBuffer buffer, required int key}) =>
+    RustLib.instance.api.crateApiBufferXorBuffer(buffer: buffer, key: key);
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NativeBuffer>>
+/// Native byte buffer that Dart can view in place through `dart:ffi`
+///
+/// Passing a `NativeBuffer` to Rust hands over an opaque handle, so large payloads
+/// skip the per-byte encoding that `Vec<u8>` arguments go through. Dart must not
+/// write to its view while a Rust call is using the buffer.
+abstract class NativeBuffer implements RustOpaqueInterface {
+  /// Address of the first byte; valid until the buffer is disposed
+  BigInt address();
+
+  bool isEmpty();
+
+  BigInt len();
+
+  /// Allocate `len` zeroed bytes
+  ///
+  /// Fails with [`PluginError::InvalidArgument`] if the memory cannot be allocated.
+  static Future<NativeBuffer> newInstance({required BigInt len}) =>
+      RustLib.instance.api.crateApiBufferNativeBufferNew(len: len);
+}
@@ -0,0 +1,21 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>>
+/// Cooperative cancellation flag created in Dart and passed to long-running calls
+///
+/// Calls observing a cancelled token stop early and fail with `PluginError::Cancelled`.
+abstract class CancellationToken implements RustOpaqueInterface {
+  /// Request cancellation of every call holding this token
+  void cancel();
+
+  bool isCancelled();
+
+  factory CancellationToken() =>
+      RustLib.instance.api.crateApiCancellationCancellationTokenNew();
+}
@@ -0,0 +1,91 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Call the `crate::api` function `name` with a JSON object of arguments keyed by parameter name
+///
+/// Returns the result as JSON; for streaming functions it is the list of streamed items,
+/// which fails with [`PluginError::InvalidArgument`] once it passes 16 MiB. Call those
+/// functions directly to consume larger streams. Functions that need handles are not
+/// available; see [`list_functions`].
+Future<String> invoke({required String name, required String argsJson}) =>
+    RustLib.instance.api.crateApiDispatchInvoke(name: name, argsJson: argsJson);
+
+/// Every function [`invoke`] can call, with its parameters
+List<FunctionSchema> listFunctions() =>
+    RustLib.instance.api.crateApiDispatchListFunctions();
+
+/// A function that [`invoke`] can call
+class FunctionSchema {
+  /// The Rust name, e.g. `calculate_fibonacci`
+  final String name;
+  final List<ParamSchema> params;
+  /// Rust type of the result, e.g. `Vec<i64>`
+  final String returns;
+  /// Type of the items of a streaming function, which `invoke` returns as a list
+  final String? stream;
+
+  const FunctionSchema({
+    required this.name,
+    required this.params,
+    required this.returns,
+    required this.stream,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      params.hashCode ^
+      returns.hashCode ^
+      stream.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FunctionSchema &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          params == other.params &&
+          returns == other.returns &&
+          stream == other.stream;
+}
+
+/// A parameter of a [`FunctionSchema`]
+class ParamSchema {
+  final String name;
+  /// Rust type, e.g. `u32` or `Option<u64>`
+  final String typeName;
+  /// Whether the argument may be left out
+  final bool optional;
+  /// Accepted values of an enum parameter, empty otherwise
+  final List<String> values;
+
+  const ParamSchema({
+    required this.name,
+    required this.typeName,
+    required this.optional,
+    required this.values,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      typeName.hashCode ^
+      optional.hashCode ^
+      values.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ParamSchema &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          typeName == other.typeName &&
+          optional == other.optional &&
+          values == other.values;
+}
@@ -0,0 +1,40 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'error.freezed.dart';
+
+/// Errors reported by the plugin API
+///
+/// Each variant arrives in Dart as its own exception class.
+@freezed
+sealed class PluginError with _$PluginError implements FrbException {
+  const PluginError._();
+
+  /// The result does not fit in the return type
+  const factory PluginError.overflow({
+    required String operation,
+  }) = PluginError_Overflow;
+  /// An argument is outside the range accepted by the function
+  const factory PluginError.invalidArgument({
+    required String name,
+    required String reason,
+  }) = PluginError_InvalidArgument;
+  /// The call was cancelled before it finished
+  const factory PluginError.cancelled() = PluginError_Cancelled;
+  /// An unexpected failure inside the plugin
+  const factory PluginError.internal({
+    required String details,
+  }) = PluginError_Internal;
+
+  /// Stable numeric code of the error, suitable for logs and analytics
+  int code() => RustLib.instance.api.crateApiErrorPluginErrorCode(that: this);
+
+  /// Human-readable description of the error
+  String message() =>
+      RustLib.instance.api.crateApiErrorPluginErrorMessage(that: this);
+}
@@ -0,0 +1,265 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'error.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$PluginError {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PluginError);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PluginError()';
+}
+
+
+}
+
+/// @nodoc
+class $PluginErrorCopyWith<$Res>  {
+$PluginErrorCopyWith(PluginError _, $Res Function(PluginError) __);
+}
+
+
+/// @nodoc
+
+
+class PluginError_Overflow extends PluginError {
+  const PluginError_Overflow({required this.operation}): super._();
+  
+ final  String operation;
+
+/// Create a copy of PluginError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$PluginError_OverflowCopyWith<PluginError_Overflow> get copyWith => _$PluginError_OverflowCopyWithImpl<PluginError_Overflow>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PluginError_Overflow&&(identical(other.operation, operation) || other.operation == operation));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,operation);
+
+@override
+String toString() {
+  return 'PluginError.overflow(operation: $operation)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $PluginError_OverflowCopyWith<$Res> implements $PluginErrorCopyWith<$Res> {
+  factory $PluginError_OverflowCopyWith(PluginError_Overflow value, $Res Function(PluginError_Overflow) _then) = _$PluginError_OverflowCopyWithImpl;
+@useResult
+$Res call({
+ String operation
+});
+
+
+
+
+}
+/// @nodoc
+class _$PluginError_OverflowCopyWithImpl<$Res>
+    implements $PluginError_OverflowCopyWith<$Res> {
+  _$PluginError_OverflowCopyWithImpl(this._self, this._then);
+
+  final PluginError_Overflow _self;
+  final $Res Function(PluginError_Overflow) _then;
+
+/// Create a copy of PluginError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? operation = null,}) {
+  return _then(PluginError_Overflow(
+operation: null == operation ? _self.operation : operation // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class PluginError_InvalidArgument extends PluginError {
+  const PluginError_InvalidArgument({required this.name,required this.reason}): super._();
+  
+ final  String name;
+ final  String reason;
+
+/// Create a copy of PluginError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$PluginError_InvalidArgumentCopyWith<PluginError_InvalidArgument> get copyWith => _$PluginError_InvalidArgumentCopyWithImpl<PluginError_InvalidArgument>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PluginError_InvalidArgument&&(identical(other.name, name) || other.name == name)&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,name,reason);
+
+@override
+String toString() {
+  return 'PluginError.invalidArgument(name: $name, reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $PluginError_InvalidArgumentCopyWith<$Res> implements $PluginErrorCopyWith<$Res> {
+  factory $PluginError_InvalidArgumentCopyWith(PluginError_InvalidArgument value, $Res Function(PluginError_InvalidArgument) _then) = _$PluginError_InvalidArgumentCopyWithImpl;
+@useResult
+$Res call({
+ String name, String reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$PluginError_InvalidArgumentCopyWithImpl<$Res>
+    implements $PluginError_InvalidArgumentCopyWith<$Res> {
+  _$PluginError_InvalidArgumentCopyWithImpl(this._self, this._then);
+
+  final PluginError_InvalidArgument _self;
+  final $Res Function(PluginError_InvalidArgument) _then;
+
+/// Create a copy of PluginError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? name = null,Object? reason = null,}) {
+  return _then(PluginError_InvalidArgument(
+name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,
+reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class PluginError_Cancelled extends PluginError {
+  const PluginError_Cancelled(): super._();
+  
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PluginError_Cancelled);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PluginError.cancelled()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class PluginError_Internal extends PluginError {
+  const PluginError_Internal({required this.details}): super._();
+  
+ final  String details;
+
+/// Create a copy of PluginError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$PluginError_InternalCopyWith<PluginError_Internal> get copyWith => _$PluginError_InternalCopyWithImpl<PluginError_Internal>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PluginError_Internal&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,details);
+
+@override
+String toString() {
+  return 'PluginError.internal(details: $details)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $PluginError_InternalCopyWith<$Res> implements $PluginErrorCopyWith<$Res> {
+  factory $PluginError_InternalCopyWith(PluginError_Internal value, $Res Function(PluginError_Internal) _then) = _$PluginError_InternalCopyWithImpl;
+@useResult
+$Res call({
+ String details
+});
+
+
+
+
+}
+/// @nodoc
+class _$PluginError_InternalCopyWithImpl<$Res>
+    implements $PluginError_InternalCopyWith<$Res> {
+  _$PluginError_InternalCopyWithImpl(this._self, this._then);
+
+  final PluginError_Internal _self;
+  final $Res Function(PluginError_Internal) _then;
+
+/// Create a copy of PluginError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? details = null,}) {
+  return _then(PluginError_Internal(
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,73 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Build and version information of the loaded native library
+BuildInfo buildInfo() => RustLib.instance.api.crateApiInfoBuildInfo();
+
+/// JSON Schema of the functions and types in `crate::api`
+///
+/// The same document is committed as `rust/schema/api.json`.
+String apiSchema() => RustLib.instance.api.crateApiInfoApiSchema();
+
+/// Identifies the native library that is actually loaded
+class BuildInfo {
+  final String crateVersion;
+  /// `git rev-parse HEAD` at build time, or `unknown`
+  final String gitCommit;
+  final String rustcVersion;
+  /// Target triple, e.g. `aarch64-linux-android`
+  final String target;
+  /// Enabled cargo features, sorted
+  final List<String> features;
+  /// `debug` or `release`
+  final String profile;
+  final String frbCodegenVersion;
+  /// Content hash of the generated bindings the library was built with
+  final int frbContentHash;
+  /// Release of the embedded IANA time zone database, e.g. `2025b`
+  final String tzdbVersion;
+
+  const BuildInfo({
+    required this.crateVersion,
+    required this.gitCommit,
+    required this.rustcVersion,
+    required this.target,
+    required this.features,
+    required this.profile,
+    required this.frbCodegenVersion,
+    required this.frbContentHash,
+    required this.tzdbVersion,
+  });
+
+  @override
+  int get hashCode =>
+      crateVersion.hashCode ^
+      gitCommit.hashCode ^
+      rustcVersion.hashCode ^
+      target.hashCode ^
+      features.hashCode ^
+      profile.hashCode ^
+      frbCodegenVersion.hashCode ^
+      frbContentHash.hashCode ^
+      tzdbVersion.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BuildInfo &&
+          runtimeType == other.runtimeType &&
+          crateVersion == other.crateVersion &&
+          gitCommit == other.gitCommit &&
+          rustcVersion == other.rustcVersion &&
+          target == other.target &&
+          features == other.features &&
+          profile == other.profile &&
+          frbCodegenVersion == other.frbCodegenVersion &&
+          frbContentHash == other.frbContentHash &&
+          tzdbVersion == other.tzdbVersion;
+}
@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Install the panic hook and logger; run automatically by `RustLib.init()`
+Future<void> initApp() => RustLib.instance.api.crateApiInitInitApp();
+
+/// Apply `config` to the plugin
+///
+/// Safe to call more than once: each call re-applies the log level and pool
+/// sizes, while `data_directory` is fixed by the first call. Fails with
+/// [`PluginError::Internal`] if the system cannot start the threads, keeping
+/// those it started.
+Future<void> initPlugin({required PluginConfig config}) =>
+    RustLib.instance.api.crateApiInitInitPlugin(config: config);
+
+/// The configuration applied by the last successful `init_plugin` call
+PluginConfig? pluginConfig() => RustLib.instance.api.crateApiInitPluginConfig();
+
+/// Verbosity of the Rust log output
+enum LogLevel {
+  off,
+  error,
+  warn,
+  info,
+  debug,
+  trace,
+  ;
+}
+
+/// Plugin configuration passed to `init_plugin`
+class PluginConfig {
+  /// Most verbose level that is logged
+  final LogLevel logLevel;
+  /// Threads in the CPU pool, at most 256; `None` uses one per CPU
+  final int? workerThreads;
+  /// Threads in the blocking I/O pool, at most 256; `None` uses 4
+  final int? blockingThreads;
+  /// Directory for files the plugin writes; created if missing
+  final String? dataDirectory;
+
+  const PluginConfig({
+    required this.logLevel,
+    required this.workerThreads,
+    required this.blockingThreads,
+    required this.dataDirectory,
+  });
+
+  @override
+  int get hashCode =>
+      logLevel.hashCode ^
+      workerThreads.hashCode ^
+      blockingThreads.hashCode ^
+      dataDirectory.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PluginConfig &&
+          runtimeType == other.runtimeType &&
+          logLevel == other.logLevel &&
+          workerThreads == other.workerThreads &&
+          blockingThreads == other.blockingThreads &&
+          dataDirectory == other.dataDirectory;
+}
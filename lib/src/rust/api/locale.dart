@@ -0,0 +1,72 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Resolve `requested`, a BCP 47 tag such as `pt-BR`, to the available translations
+///
+/// Tags that do not parse or match nothing resolve to `en`.
+LocaleInfo negotiateLocale({required String requested}) =>
+    RustLib.instance.api.crateApiLocaleNegotiateLocale(requested: requested);
+
+/// Every locale with translations, including those added with `add_translations`
+List<String> supportedLocales() =>
+    RustLib.instance.api.crateApiLocaleSupportedLocales();
+
+/// Add Fluent (`.ftl`) messages for `locale`, replacing bundled ones with the same id
+///
+/// A locale without bundled translations becomes available. Fails with
+/// [`PluginError::InvalidArgument`] for a malformed tag or FTL syntax errors.
+Future<void> addTranslations({required String locale, required String ftl}) =>
+    RustLib.instance.api.crateApiLocaleAddTranslations(
+      locale: locale,
+      ftl: ftl,
+    );
+
+/// Grammatical gender of the person addressed, for languages whose wording depends on it
+enum Gender {
+  feminine,
+  masculine,
+  /// Unknown or neither; the wording avoids gendered forms
+  other,
+  ;
+}
+
+/// How a requested locale is resolved against the available translations
+class LocaleInfo {
+  /// Best available locale, e.g. `pt-BR`
+  final String locale;
+  /// Locales messages are looked up in, best first, e.g. `pt-BR`, `pt`, `en`
+  final List<String> fallbacks;
+  /// Direction of text localized for `locale`; lay out its widgets to match
+  final WritingDirection direction;
+
+  const LocaleInfo({
+    required this.locale,
+    required this.fallbacks,
+    required this.direction,
+  });
+
+  @override
+  int get hashCode => locale.hashCode ^ fallbacks.hashCode ^ direction.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LocaleInfo &&
+          runtimeType == other.runtimeType &&
+          locale == other.locale &&
+          fallbacks == other.fallbacks &&
+          direction == other.direction;
+}
+
+/// Direction in which a locale's script is written
+enum WritingDirection {
+  ltr,
+  rtl,
+  ;
+}
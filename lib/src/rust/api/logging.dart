@@ -0,0 +1,85 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'init.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Stream log records to Dart until the subscription is cancelled
+Stream<LogRecord> subscribeLogs() =>
+    RustLib.instance.api.crateApiLoggingSubscribeLogs();
+
+/// Set the level for targets without a more specific override
+void setLogLevel({required LogLevel level}) =>
+    RustLib.instance.api.crateApiLoggingSetLogLevel(level: level);
+
+/// Set the level for `target` and its submodules, e.g. `demo_rust_native_plugin::api::simple`
+void setLogTargetLevel({required String target, required LogLevel level}) =>
+    RustLib.instance.api.crateApiLoggingSetLogTargetLevel(
+      target: target,
+      level: level,
+    );
+
+/// Remove every per-target level set with `set_log_target_level`
+void clearLogTargetLevels() =>
+    RustLib.instance.api.crateApiLoggingClearLogTargetLevels();
+
+/// A key/value field of a log record
+class LogField {
+  final String key;
+  final String value;
+
+  const LogField({required this.key, required this.value});
+
+  @override
+  int get hashCode => key.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LogField &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          value == other.value;
+}
+
+/// A log record emitted by the Rust crate
+class LogRecord {
+  final LogLevel level;
+  /// Module path of the code that logged, unless overridden with `target:`
+  final String target;
+  final String message;
+  /// Milliseconds since the Unix epoch
+  final PlatformInt64 timestampMillis;
+  /// Structured key/value fields attached to the record
+  final List<LogField> fields;
+
+  const LogRecord({
+    required this.level,
+    required this.target,
+    required this.message,
+    required this.timestampMillis,
+    required this.fields,
+  });
+
+  @override
+  int get hashCode =>
+      level.hashCode ^
+      target.hashCode ^
+      message.hashCode ^
+      timestampMillis.hashCode ^
+      fields.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LogRecord &&
+          runtimeType == other.runtimeType &&
+          level == other.level &&
+          target == other.target &&
+          message == other.message &&
+          timestampMillis == other.timestampMillis &&
+          fields == other.fields;
+}
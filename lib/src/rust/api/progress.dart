@@ -0,0 +1,41 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ProgressReporter>>
+/// Side channel for long-running calls to report progress to Dart
+///
+/// Updates closer together than `min_interval_ms` are dropped, except the final one.
+abstract class ProgressReporter implements RustOpaqueInterface {
+  /// Stream the updates reported through this reporter, replacing any earlier subscription
+  Stream<ProgressUpdate> updates();
+
+  factory ProgressReporter({required int minIntervalMs}) =>
+      RustLib.instance.api.crateApiProgressProgressReporterNew(
+        minIntervalMs: minIntervalMs,
+      );
+}
+
+/// A progress update from a long-running call
+class ProgressUpdate {
+  /// Completed fraction of the work, from 0.0 to 1.0
+  final double fraction;
+  final String? message;
+
+  const ProgressUpdate({required this.fraction, required this.message});
+
+  @override
+  int get hashCode => fraction.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProgressUpdate &&
+          runtimeType == other.runtimeType &&
+          fraction == other.fraction &&
+          message == other.message;
+}
@@ -4,18 +4,309 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'cancellation.dart';
+import 'error.dart';
+import 'locale.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'progress.dart';
+import 'style.dart';
+part 'simple.freezed.dart';
 
-/// Simple greeting function
-Future<String> greet({required String name}) =>
-    RustLib.instance.api.crateApiSimpleGreet(name: name);
+/// Greet `name` in `locale`, a BCP 47 tag such as `pt-BR`
+///
+/// `name` is cleaned up with `process_name` first, and fails the same way.
+/// Unavailable locales fall back as in `negotiate_locale`, ending with English. `gender`
+/// picks the wording in languages that need it and defaults to `Gender::Other`. With
+/// `time_zone`, an IANA name such as `America/Sao_Paulo`, the greeting depends on the
+/// time of day there at `now_utc_millis`, or at the current time without it; see
+/// `time_of_day`. With `template`, the greeting is rendered from the template
+/// registered under that name with `register_greeting_template`; templates that use
+/// `time_of_day` need `time_zone`. `style` sets the decoration, 🦀 by default. Unknown
+/// time zones and templates fail with [`PluginError::InvalidArgument`].
+///
+/// `greet_sync` covers greetings without templates or time zones.
+Future<String> greet({
+  required String name,
+  String? locale,
+  Gender? gender,
+  String? template,
+  GreetingStyle? style,
+  String? timeZone,
+  PlatformInt64? nowUtcMillis,
+}) => RustLib.instance.api.crateApiSimpleGreet(
+  name: name,
+  locale: locale,
+  gender: gender,
+  template: template,
+  style: style,
+  timeZone: timeZone,
+  nowUtcMillis: nowUtcMillis,
+);
+
+/// Remove control, invisible and bidirectional formatting characters, collapse
+/// whitespace, NFC-normalize the rest and check for mixed-script words
+///
+/// Fails with [`PluginError::InvalidArgument`] if nothing is left, or if the result has
+/// more than `max_graphemes` user-perceived characters (64 by default).
+Future<ProcessedName> processName({required String name, int? maxGraphemes}) =>
+    RustLib.instance.api.crateApiSimpleProcessName(
+      name: name,
+      maxGraphemes: maxGraphemes,
+    );
 
 /// Calculate Fibonacci number
+///
+/// Fails with [`PluginError::Overflow`] for `n > 93`; use `calculate_fibonacci_big` there.
 Future<BigInt> calculateFibonacci({required int n}) =>
     RustLib.instance.api.crateApiSimpleCalculateFibonacci(n: n);
 
+/// Calculate Fibonacci number exactly, optionally reduced modulo `modulus`
+///
+/// The value is returned as a decimal string (parse it with `BigInt.parse` in Dart).
+/// A modulus of zero leaves the result unreduced.
+Future<String> calculateFibonacciBig({
+  required int n,
+  BigInt? modulus,
+  required CancellationToken cancellation,
+  required ProgressReporter progress,
+}) => RustLib.instance.api.crateApiSimpleCalculateFibonacciBig(
+  n: n,
+  modulus: modulus,
+  cancellation: cancellation,
+  progress: progress,
+);
+
+/// Stream `count` Fibonacci numbers starting at `F(start)`, `chunk_size` terms per event
+///
+/// A `chunk_size` of 1 emits the sequence term by term; at most 1024 are allowed. A chunk
+/// ends early once its digits pass 1 MiB, so long terms are sent as they are ready.
+/// Generation stops once the Dart subscription is cancelled, which is noticed at the next
+/// event; `cancellation` stops it between any two terms.
+Stream<FibonacciChunk> fibonacciSequence({
+  required int start,
+  required int count,
+  required int chunkSize,
+  required CancellationToken cancellation,
+}) => RustLib.instance.api.crateApiSimpleFibonacciSequence(
+  start: start,
+  count: count,
+  chunkSize: chunkSize,
+  cancellation: cancellation,
+);
+
 /// Add two numbers (example with multiple parameters)
 Future<PlatformInt64> addNumbers({
   required PlatformInt64 a,
   required PlatformInt64 b,
-}) => RustLib.instance.api.crateApiSimpleAddNumbers(a: a, b: b);
+  required OverflowPolicy policy,
+}) => RustLib.instance.api.crateApiSimpleAddNumbers(a: a, b: b, policy: policy);
+
+/// Subtract `b` from `a`
+Future<PlatformInt64> subtractNumbers({
+  required PlatformInt64 a,
+  required PlatformInt64 b,
+  required OverflowPolicy policy,
+}) => RustLib.instance.api.crateApiSimpleSubtractNumbers(
+  a: a,
+  b: b,
+  policy: policy,
+);
+
+/// Multiply two numbers
+Future<PlatformInt64> multiplyNumbers({
+  required PlatformInt64 a,
+  required PlatformInt64 b,
+  required OverflowPolicy policy,
+}) => RustLib.instance.api.crateApiSimpleMultiplyNumbers(
+  a: a,
+  b: b,
+  policy: policy,
+);
+
+/// Divide `a` by `b`, rounding toward zero
+///
+/// Division by zero is an invalid argument under every policy; only `i64::MIN / -1` overflows.
+Future<PlatformInt64> divideNumbers({
+  required PlatformInt64 a,
+  required PlatformInt64 b,
+  required OverflowPolicy policy,
+}) => RustLib.instance.api.crateApiSimpleDivideNumbers(
+  a: a,
+  b: b,
+  policy: policy,
+);
+
+/// Raise `base` to the power `exponent`
+Future<PlatformInt64> powNumber({
+  required PlatformInt64 base,
+  required int exponent,
+  required OverflowPolicy policy,
+}) => RustLib.instance.api.crateApiSimplePowNumber(
+  base: base,
+  exponent: exponent,
+  policy: policy,
+);
+
+/// Synchronous variant of `greet` without templates or time zones
+///
+/// `name`, `locale` and the style's `prefix` and `suffix` may have at most 1 KiB each;
+/// longer ones fail with [`PluginError::InvalidArgument`], and `greet` takes them.
+String greetSync({
+  required String name,
+  String? locale,
+  Gender? gender,
+  GreetingStyle? style,
+}) => RustLib.instance.api.crateApiSimpleGreetSync(
+  name: name,
+  locale: locale,
+  gender: gender,
+  style: style,
+);
+
+/// Synchronous variant of `calculate_fibonacci`; at most 93 additions
+BigInt calculateFibonacciSync({required int n}) =>
+    RustLib.instance.api.crateApiSimpleCalculateFibonacciSync(n: n);
+
+/// Synchronous variant of `add_numbers`
+PlatformInt64 addNumbersSync({
+  required PlatformInt64 a,
+  required PlatformInt64 b,
+  required OverflowPolicy policy,
+}) => RustLib.instance.api.crateApiSimpleAddNumbersSync(
+  a: a,
+  b: b,
+  policy: policy,
+);
+
+/// Synchronous variant of `subtract_numbers`
+PlatformInt64 subtractNumbersSync({
+  required PlatformInt64 a,
+  required PlatformInt64 b,
+  required OverflowPolicy policy,
+}) => RustLib.instance.api.crateApiSimpleSubtractNumbersSync(
+  a: a,
+  b: b,
+  policy: policy,
+);
+
+/// Synchronous variant of `multiply_numbers`
+PlatformInt64 multiplyNumbersSync({
+  required PlatformInt64 a,
+  required PlatformInt64 b,
+  required OverflowPolicy policy,
+}) => RustLib.instance.api.crateApiSimpleMultiplyNumbersSync(
+  a: a,
+  b: b,
+  policy: policy,
+);
+
+/// Synchronous variant of `divide_numbers`
+PlatformInt64 divideNumbersSync({
+  required PlatformInt64 a,
+  required PlatformInt64 b,
+  required OverflowPolicy policy,
+}) => RustLib.instance.api.crateApiSimpleDivideNumbersSync(
+  a: a,
+  b: b,
+  policy: policy,
+);
+
+/// Synchronous variant of `pow_number`
+PlatformInt64 powNumberSync({
+  required PlatformInt64 base,
+  required int exponent,
+  required OverflowPolicy policy,
+}) => RustLib.instance.api.crateApiSimplePowNumberSync(
+  base: base,
+  exponent: exponent,
+  policy: policy,
+);
+
+/// A run of consecutive Fibonacci numbers emitted by `fibonacci_sequence`
+class FibonacciChunk {
+  /// Index `n` of the first term
+  final int start;
+  /// `F(start)`, `F(start + 1)`, ... as decimal strings
+  final List<String> terms;
+
+  const FibonacciChunk({required this.start, required this.terms});
+
+  @override
+  int get hashCode => start.hashCode ^ terms.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FibonacciChunk &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          terms == other.terms;
+}
+
+/// Something `process_name` changed or noticed about a name
+@freezed
+sealed class NameWarning with _$NameWarning {
+  const NameWarning._();
+
+  /// The name, without the removed characters, was not in Unicode normalization form C
+  const factory NameWarning.normalized() = NameWarning_Normalized;
+  /// Control, invisible or bidirectional formatting characters (e.g. U+202E
+  /// RIGHT-TO-LEFT OVERRIDE) were removed
+  const factory NameWarning.removedCharacters({
+    required Uint32List codePoints,
+  }) = NameWarning_RemovedCharacters;
+  /// A word mixes scripts, e.g. Latin letters with Cyrillic look-alikes, which can
+  /// make it pass for a different name
+  const factory NameWarning.mixedScript({
+    required String word,
+    required List<String> scripts,
+  }) = NameWarning_MixedScript;
+}
+
+/// How integer arithmetic behaves when the exact result does not fit in `i64`
+///
+/// The chosen policy is applied explicitly, so results are identical in debug and release builds.
+enum OverflowPolicy {
+  /// Fail with `PluginError::Overflow`
+  checked,
+  /// Clamp to `i64::MIN` or `i64::MAX`
+  saturating,
+  /// Wrap around in two's complement
+  wrapping,
+  ;
+}
+
+/// A name cleaned up for display to other users
+class ProcessedName {
+  final String name;
+  /// User-perceived characters (grapheme clusters) in `name`
+  final int graphemes;
+  /// Unicode scripts of the letters in `name`, e.g. `Latin`, in order of first use
+  final List<String> scripts;
+  final List<NameWarning> warnings;
+
+  const ProcessedName({
+    required this.name,
+    required this.graphemes,
+    required this.scripts,
+    required this.warnings,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      graphemes.hashCode ^
+      scripts.hashCode ^
+      warnings.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessedName &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          graphemes == other.graphemes &&
+          scripts == other.scripts &&
+          warnings == other.warnings;
+}
@@ -0,0 +1,200 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'simple.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$NameWarning {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NameWarning);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'NameWarning()';
+}
+
+
+}
+
+/// @nodoc
+class $NameWarningCopyWith<$Res>  {
+$NameWarningCopyWith(NameWarning _, $Res Function(NameWarning) __);
+}
+
+
+/// @nodoc
+
+
+class NameWarning_Normalized extends NameWarning {
+  const NameWarning_Normalized(): super._();
+  
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NameWarning_Normalized);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'NameWarning.normalized()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class NameWarning_RemovedCharacters extends NameWarning {
+  const NameWarning_RemovedCharacters({required this.codePoints}): super._();
+  
+ final  Uint32List codePoints;
+
+/// Create a copy of NameWarning
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NameWarning_RemovedCharactersCopyWith<NameWarning_RemovedCharacters> get copyWith => _$NameWarning_RemovedCharactersCopyWithImpl<NameWarning_RemovedCharacters>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NameWarning_RemovedCharacters&&(identical(other.codePoints, codePoints) || other.codePoints == codePoints));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,codePoints);
+
+@override
+String toString() {
+  return 'NameWarning.removedCharacters(codePoints: $codePoints)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NameWarning_RemovedCharactersCopyWith<$Res> implements $NameWarningCopyWith<$Res> {
+  factory $NameWarning_RemovedCharactersCopyWith(NameWarning_RemovedCharacters value, $Res Function(NameWarning_RemovedCharacters) _then) = _$NameWarning_RemovedCharactersCopyWithImpl;
+@useResult
+$Res call({
+ Uint32List codePoints
+});
+
+
+
+
+}
+/// @nodoc
+class _$NameWarning_RemovedCharactersCopyWithImpl<$Res>
+    implements $NameWarning_RemovedCharactersCopyWith<$Res> {
+  _$NameWarning_RemovedCharactersCopyWithImpl(this._self, this._then);
+
+  final NameWarning_RemovedCharacters _self;
+  final $Res Function(NameWarning_RemovedCharacters) _then;
+
+/// Create a copy of NameWarning
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? codePoints = null,}) {
+  return _then(NameWarning_RemovedCharacters(
+codePoints: null == codePoints ? _self.codePoints : codePoints // ignore: cast_nullable_to_non_nullable
+as Uint32List,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class NameWarning_MixedScript extends NameWarning {
+  const NameWarning_MixedScript({required this.word,required this.scripts}): super._();
+  
+ final  String word;
+ final  List<String> scripts;
+
+/// Create a copy of NameWarning
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$NameWarning_MixedScriptCopyWith<NameWarning_MixedScript> get copyWith => _$NameWarning_MixedScriptCopyWithImpl<NameWarning_MixedScript>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is NameWarning_MixedScript&&(identical(other.word, word) || other.word == word)&&(identical(other.scripts, scripts) || other.scripts == scripts));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,word,scripts);
+
+@override
+String toString() {
+  return 'NameWarning.mixedScript(word: $word, scripts: $scripts)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $NameWarning_MixedScriptCopyWith<$Res> implements $NameWarningCopyWith<$Res> {
+  factory $NameWarning_MixedScriptCopyWith(NameWarning_MixedScript value, $Res Function(NameWarning_MixedScript) _then) = _$NameWarning_MixedScriptCopyWithImpl;
+@useResult
+$Res call({
+ String word, List<String> scripts
+});
+
+
+
+
+}
+/// @nodoc
+class _$NameWarning_MixedScriptCopyWithImpl<$Res>
+    implements $NameWarning_MixedScriptCopyWith<$Res> {
+  _$NameWarning_MixedScriptCopyWithImpl(this._self, this._then);
+
+  final NameWarning_MixedScript _self;
+  final $Res Function(NameWarning_MixedScript) _then;
+
+/// Create a copy of NameWarning
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? word = null,Object? scripts = null,}) {
+  return _then(NameWarning_MixedScript(
+word: null == word ? _self.word : word // ignore: cast_nullable_to_non_nullable
+as String,
+scripts: null == scripts ? _self.scripts : scripts // ignore: cast_nullable_to_non_nullable
+as List<String>,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,115 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Check whether `emoji` is likely to render on a system whose fonts support emoji up
+/// to `supported_version`, e.g. `13.1`
+///
+/// Without `supported_version`, assumes 12.0 (Android 10, iOS 13). Emoji sequences
+/// newer than the fonts usually fall apart into their parts or show as boxes. Fails
+/// with [`PluginError::InvalidArgument`] if `supported_version` is not `major.minor`.
+EmojiSupport emojiSupport({required String emoji, String? supportedVersion}) =>
+    RustLib.instance.api.crateApiStyleEmojiSupport(
+      emoji: emoji,
+      supportedVersion: supportedVersion,
+    );
+
+/// Decoration `greet` adds after the greeting
+enum EmojiSet {
+  none,
+  /// 🦀, the default
+  crab,
+  /// 👋
+  wave,
+  /// 🎉
+  party,
+  /// ✨
+  sparkles,
+  /// 💖
+  heart,
+  ;
+}
+
+/// What is known about rendering one emoji
+class EmojiSupport {
+  /// CLDR name, e.g. `crab`; `None` if the text is not a single emoji
+  final String? name;
+  /// Emoji version that introduced it, e.g. `1.0`
+  final String? emojiVersion;
+  /// Whether fonts supporting `supported_version` draw it as one picture
+  final bool likelyRenderable;
+
+  const EmojiSupport({
+    required this.name,
+    required this.emojiVersion,
+    required this.likelyRenderable,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      emojiVersion.hashCode ^
+      likelyRenderable.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EmojiSupport &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          emojiVersion == other.emojiVersion &&
+          likelyRenderable == other.likelyRenderable;
+}
+
+/// How `greet` decorates the greeting
+class GreetingStyle {
+  /// `EmojiSet::Crab` when unset
+  final EmojiSet? emoji;
+  /// `TextMode::Unicode` when unset
+  final TextMode? mode;
+  /// Text before the greeting, as is
+  final String? prefix;
+  /// Text after the greeting and its emoji, as is
+  final String? suffix;
+
+  const GreetingStyle({
+    required this.emoji,
+    required this.mode,
+    required this.prefix,
+    required this.suffix,
+  });
+
+  @override
+  int get hashCode =>
+      emoji.hashCode ^
+      mode.hashCode ^
+      prefix.hashCode ^
+      suffix.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GreetingStyle &&
+          runtimeType == other.runtimeType &&
+          emoji == other.emoji &&
+          mode == other.mode &&
+          prefix == other.prefix &&
+          suffix == other.suffix;
+}
+
+/// Characters `greet` may produce
+enum TextMode {
+  /// Any Unicode, the default
+  unicode,
+  /// Printable ASCII only, for terminals: text is transliterated (`こんにちは` becomes
+  /// `konnitiha`) and the decoration is drawn in ASCII, e.g. `o/`
+  ascii,
+  /// No decoration, which screen readers would read out, e.g. "crab"
+  screenReader,
+  ;
+}
@@ -0,0 +1,37 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Register a greeting template that `greet` renders when passed its `name`
+///
+/// `source` uses Jinja syntax, e.g. `{{ salutation }} {{ name | title }}, good {{ time_of_day }}`,
+/// with `{% if gender == "feminine" %}...{% endif %}` conditionals and the MiniJinja
+/// filters (`upper`, `title`, `trim`, `default`, ...). Templates can use `name`,
+/// `salutation`, `greeting`, `locale`, `gender` and `time_of_day`; `greet` fails for
+/// templates that use `time_of_day` without a time zone. A template with the same name
+/// is replaced. Fails with [`PluginError::InvalidArgument`] on syntax errors
+/// and unknown variables, and on unknown filters in any branch that only depends on
+/// `gender` and `time_of_day`, since registering renders the template for each of their
+/// values.
+Future<void> registerGreetingTemplate({
+  required String name,
+  required String source,
+}) => RustLib.instance.api.crateApiTemplatesRegisterGreetingTemplate(
+  name: name,
+  source: source,
+);
+
+/// Remove a greeting template; returns whether it was registered
+bool unregisterGreetingTemplate({required String name}) =>
+    RustLib.instance.api.crateApiTemplatesUnregisterGreetingTemplate(
+      name: name,
+    );
+
+/// Names of the registered greeting templates, sorted
+List<String> greetingTemplates() =>
+    RustLib.instance.api.crateApiTemplatesGreetingTemplates();
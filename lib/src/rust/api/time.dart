@@ -0,0 +1,34 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// The part of the day in `time_zone`, an IANA name such as `Europe/Lisbon`
+///
+/// At `now_utc_millis`, milliseconds since the Unix epoch, or at the current time
+/// without it. Uses the time zone database embedded in the crate (see
+/// `BuildInfo::tzdb_version`), so the result does not depend on the device's. Fails
+/// with [`PluginError::InvalidArgument`] for unknown time zones and instants chrono
+/// cannot represent.
+TimeOfDay timeOfDay({required String timeZone, PlatformInt64? nowUtcMillis}) =>
+    RustLib.instance.api.crateApiTimeTimeOfDay(
+      timeZone: timeZone,
+      nowUtcMillis: nowUtcMillis,
+    );
+
+/// Part of the day, which picks between "Good morning", "Good afternoon" and "Good evening"
+enum TimeOfDay {
+  /// 05:00 to 11:59
+  morning,
+  /// 12:00 to 16:59
+  afternoon,
+  /// 17:00 to 21:59
+  evening,
+  /// 22:00 to 04:59
+  night,
+  ;
+}
@@ -0,0 +1,92 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Run every future call of `function` on `pool`, with `priority` unless the call sets its own
+///
+/// `function` is the Rust name, e.g. `calculate_fibonacci_big`, or `Type_method` for methods.
+/// By default short calls such as `greet` run at `Interactive` priority, long computations
+/// at `Background` priority and `init_plugin` on the blocking pool; everything else runs on
+/// the CPU pool at `Normal` priority.
+void setTaskRoute({
+  required String function,
+  required WorkerPoolKind pool,
+  required TaskPriority priority,
+}) => RustLib.instance.api.crateApiWorkersSetTaskRoute(
+  function: function,
+  pool: pool,
+  priority: priority,
+);
+
+/// Queue the next async call made from this thread at `priority`, whatever its route says
+///
+/// Applies to one call only; `None` clears a priority no call has used yet. From Dart,
+/// use `withTaskPriority`, which makes the call right away on the same thread.
+void setNextCallPriority({TaskPriority? priority}) =>
+    RustLib.instance.api.crateApiWorkersSetNextCallPriority(priority: priority);
+
+/// Current load of the CPU and blocking pools
+List<WorkerPoolMetrics> workerPoolMetrics() =>
+    RustLib.instance.api.crateApiWorkersWorkerPoolMetrics();
+
+/// Queue priority of an async call; higher priorities are started first, and a call
+/// waiting more than two seconds starts before any that arrived after it
+enum TaskPriority {
+  background,
+  normal,
+  interactive,
+  ;
+}
+
+/// Thread pool that runs an async call
+enum WorkerPoolKind {
+  /// Computation; sized to the number of CPUs by default
+  cpu,
+  /// Calls that wait on I/O or locks
+  blocking,
+  ;
+}
+
+/// Snapshot of one worker pool
+class WorkerPoolMetrics {
+  final WorkerPoolKind pool;
+  /// Zero where calls run on the calling thread, as on the web without web workers
+  final int threads;
+  /// Threads currently running a call
+  final int busy;
+  /// Calls waiting for a free thread; non-zero means the pool is saturated
+  final int queued;
+  /// Calls finished since startup
+  final BigInt completed;
+
+  const WorkerPoolMetrics({
+    required this.pool,
+    required this.threads,
+    required this.busy,
+    required this.queued,
+    required this.completed,
+  });
+
+  @override
+  int get hashCode =>
+      pool.hashCode ^
+      threads.hashCode ^
+      busy.hashCode ^
+      queued.hashCode ^
+      completed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WorkerPoolMetrics &&
+          runtimeType == other.runtimeType &&
+          pool == other.pool &&
+          threads == other.threads &&
+          busy == other.busy &&
+          queued == other.queued &&
+          completed == other.completed;
+}
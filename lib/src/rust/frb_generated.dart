@@ -3,7 +3,21 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/batch.dart';
+import 'api/buffer.dart';
+import 'api/cancellation.dart';
+import 'api/dispatch.dart';
+import 'api/error.dart';
+import 'api/info.dart';
+import 'api/init.dart';
+import 'api/locale.dart';
+import 'api/logging.dart';
+import 'api/progress.dart';
 import 'api/simple.dart';
+import 'api/style.dart';
+import 'api/templates.dart';
+import 'api/time.dart';
+import 'api/workers.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -54,7 +68,9 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
       RustLibWire.fromExternalLibrary;
 
   @override
-  Future<void> executeRustInitializers() async {}
+  Future<void> executeRustInitializers() async {
+    await api.crateApiInitInitApp();
+  }
 
   @override
   ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
@@ -64,7 +80,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -1629401012;
+  int get rustContentHash => 454131765;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -75,229 +91,4411 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<Float64List> crateApiBatchBatchF64({
+    required List<double> a,
+    required List<double> b,
+    required BatchOp op,
+  });
+
+  Future<Int64List> crateApiBatchBatchI64({
+    required Int64List a,
+    required Int64List b,
+    required BatchOp op,
+    required OverflowPolicy policy,
+  });
+
+  Future<double> crateApiBatchDotProductF64({
+    required List<double> a,
+    required List<double> b,
+  });
+
+  Future<PlatformInt64> crateApiBatchDotProductI64({
+    required Int64List a,
+    required Int64List b,
+    required OverflowPolicy policy,
+  });
+
+  Future<Float64List> crateApiBatchPrefixSumsF64({
+    required List<double> values,
+  });
+
+  Future<Int64List> crateApiBatchPrefixSumsI64({
+    required Int64List values,
+    required OverflowPolicy policy,
+  });
+
+  Future<double> crateApiBatchReduceF64({
+    required List<double> values,
+    required Reduction reduction,
+  });
+
+  Future<PlatformInt64> crateApiBatchReduceI64({
+    required Int64List values,
+    required Reduction reduction,
+    required OverflowPolicy policy,
+  });
+
+  BigInt crateApiBufferNativeBufferAddress({required NativeBuffer that});
+
+  bool crateApiBufferNativeBufferIsEmpty({required NativeBuffer that});
+
+  BigInt crateApiBufferNativeBufferLen({required NativeBuffer that});
+
+  Future<NativeBuffer> crateApiBufferNativeBufferNew({required BigInt len});
+
+  Future<int> crateApiBufferChecksumBuffer({required NativeBuffer buffer});
+
+  Future<int> crateApiBufferChecksumBytes({required List<int> data});
+
+  Future<void> crateApiBufferXorBuffer({
+    required NativeBuffer buffer,
+    required int key,
+  });
+
+  Future<Uint8List> crateApiBufferXorBytes({
+    required List<int> data,
+    required int key,
+  });
+
+  void crateApiCancellationCancellationTokenCancel({
+    required CancellationToken that,
+  });
+
+  bool crateApiCancellationCancellationTokenIsCancelled({
+    required CancellationToken that,
+  });
+
+  CancellationToken crateApiCancellationCancellationTokenNew();
+
+  Future<String> crateApiDispatchInvoke({
+    required String name,
+    required String argsJson,
+  });
+
+  List<FunctionSchema> crateApiDispatchListFunctions();
+
+  int crateApiErrorPluginErrorCode({required PluginError that});
+
+  String crateApiErrorPluginErrorMessage({required PluginError that});
+
+  String crateApiInfoApiSchema();
+
+  BuildInfo crateApiInfoBuildInfo();
+
+  Future<void> crateApiInitInitApp();
+
+  Future<void> crateApiInitInitPlugin({required PluginConfig config});
+
+  PluginConfig? crateApiInitPluginConfig();
+
+  Future<void> crateApiLocaleAddTranslations({
+    required String locale,
+    required String ftl,
+  });
+
+  LocaleInfo crateApiLocaleNegotiateLocale({required String requested});
+
+  List<String> crateApiLocaleSupportedLocales();
+
+  void crateApiLoggingClearLogTargetLevels();
+
+  void crateApiLoggingSetLogLevel({required LogLevel level});
+
+  void crateApiLoggingSetLogTargetLevel({
+    required String target,
+    required LogLevel level,
+  });
+
+  Stream<LogRecord> crateApiLoggingSubscribeLogs();
+
+  ProgressReporter crateApiProgressProgressReporterNew({
+    required int minIntervalMs,
+  });
+
+  Stream<ProgressUpdate> crateApiProgressProgressReporterUpdates({
+    required ProgressReporter that,
+  });
+
   Future<PlatformInt64> crateApiSimpleAddNumbers({
     required PlatformInt64 a,
     required PlatformInt64 b,
+    required OverflowPolicy policy,
+  });
+
+  PlatformInt64 crateApiSimpleAddNumbersSync({
+    required PlatformInt64 a,
+    required PlatformInt64 b,
+    required OverflowPolicy policy,
   });
 
   Future<BigInt> crateApiSimpleCalculateFibonacci({required int n});
 
-  Future<String> crateApiSimpleGreet({required String name});
-}
+  Future<String> crateApiSimpleCalculateFibonacciBig({
+    required int n,
+    BigInt? modulus,
+    required CancellationToken cancellation,
+    required ProgressReporter progress,
+  });
+
+  BigInt crateApiSimpleCalculateFibonacciSync({required int n});
+
+  Future<PlatformInt64> crateApiSimpleDivideNumbers({
+    required PlatformInt64 a,
+    required PlatformInt64 b,
+    required OverflowPolicy policy,
+  });
+
+  PlatformInt64 crateApiSimpleDivideNumbersSync({
+    required PlatformInt64 a,
+    required PlatformInt64 b,
+    required OverflowPolicy policy,
+  });
+
+  Stream<FibonacciChunk> crateApiSimpleFibonacciSequence({
+    required int start,
+    required int count,
+    required int chunkSize,
+    required CancellationToken cancellation,
+  });
+
+  Future<String> crateApiSimpleGreet({
+    required String name,
+    String? locale,
+    Gender? gender,
+    String? template,
+    GreetingStyle? style,
+    String? timeZone,
+    PlatformInt64? nowUtcMillis,
+  });
+
+  String crateApiSimpleGreetSync({
+    required String name,
+    String? locale,
+    Gender? gender,
+    GreetingStyle? style,
+  });
+
+  Future<PlatformInt64> crateApiSimpleMultiplyNumbers({
+    required PlatformInt64 a,
+    required PlatformInt64 b,
+    required OverflowPolicy policy,
+  });
+
+  PlatformInt64 crateApiSimpleMultiplyNumbersSync({
+    required PlatformInt64 a,
+    required PlatformInt64 b,
+    required OverflowPolicy policy,
+  });
+
+  Future<PlatformInt64> crateApiSimplePowNumber({
+    required PlatformInt64 base,
+    required int exponent,
+    required OverflowPolicy policy,
+  });
+
+  PlatformInt64 crateApiSimplePowNumberSync({
+    required PlatformInt64 base,
+    required int exponent,
+    required OverflowPolicy policy,
+  });
+
+  Future<ProcessedName> crateApiSimpleProcessName({
+    required String name,
+    int? maxGraphemes,
+  });
+
+  Future<PlatformInt64> crateApiSimpleSubtractNumbers({
+    required PlatformInt64 a,
+    required PlatformInt64 b,
+    required OverflowPolicy policy,
+  });
+
+  PlatformInt64 crateApiSimpleSubtractNumbersSync({
+    required PlatformInt64 a,
+    required PlatformInt64 b,
+    required OverflowPolicy policy,
+  });
+
+  EmojiSupport crateApiStyleEmojiSupport({
+    required String emoji,
+    String? supportedVersion,
+  });
+
+  List<String> crateApiTemplatesGreetingTemplates();
+
+  Future<void> crateApiTemplatesRegisterGreetingTemplate({
+    required String name,
+    required String source,
+  });
+
+  bool crateApiTemplatesUnregisterGreetingTemplate({required String name});
+
+  TimeOfDay crateApiTimeTimeOfDay({
+    required String timeZone,
+    PlatformInt64? nowUtcMillis,
+  });
+
+  void crateApiWorkersSetNextCallPriority({TaskPriority? priority});
+
+  void crateApiWorkersSetTaskRoute({
+    required String function,
+    required WorkerPoolKind pool,
+    required TaskPriority priority,
+  });
+
+  List<WorkerPoolMetrics> crateApiWorkersWorkerPoolMetrics();
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_CancellationToken;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_CancellationToken;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_CancellationTokenPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_NativeBuffer;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_NativeBuffer;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_NativeBufferPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ProgressReporter;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ProgressReporter;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_ProgressReporterPtr;
+}
+
+class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+  RustLibApiImpl({
+    required super.handler,
+    required super.wire,
+    required super.generalizedFrbRustBinding,
+    required super.portManager,
+  });
+
+  @override
+  Future<Float64List> crateApiBatchBatchF64({
+    required List<double> a,
+    required List<double> b,
+    required BatchOp op,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_f_64_loose(a, serializer);
+          sse_encode_list_prim_f_64_loose(b, serializer);
+          sse_encode_batch_op(op, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 1,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_f_64_strict,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiBatchBatchF64ConstMeta,
+        argValues: [a, b, op],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBatchBatchF64ConstMeta =>
+      const TaskConstMeta(debugName: "batch_f64", argNames: ["a", "b", "op"]);
+
+  @override
+  Future<Int64List> crateApiBatchBatchI64({
+    required Int64List a,
+    required Int64List b,
+    required BatchOp op,
+    required OverflowPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_i_64_loose(a, serializer);
+          sse_encode_list_prim_i_64_loose(b, serializer);
+          sse_encode_batch_op(op, serializer);
+          sse_encode_overflow_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 2,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_i_64_strict,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiBatchBatchI64ConstMeta,
+        argValues: [a, b, op, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBatchBatchI64ConstMeta =>
+      const TaskConstMeta(
+        debugName: "batch_i64",
+        argNames: ["a", "b", "op", "policy"],
+      );
+
+  @override
+  Future<double> crateApiBatchDotProductF64({
+    required List<double> a,
+    required List<double> b,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_f_64_loose(a, serializer);
+          sse_encode_list_prim_f_64_loose(b, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 3,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiBatchDotProductF64ConstMeta,
+        argValues: [a, b],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBatchDotProductF64ConstMeta =>
+      const TaskConstMeta(debugName: "dot_product_f64", argNames: ["a", "b"]);
+
+  @override
+  Future<PlatformInt64> crateApiBatchDotProductI64({
+    required Int64List a,
+    required Int64List b,
+    required OverflowPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_i_64_loose(a, serializer);
+          sse_encode_list_prim_i_64_loose(b, serializer);
+          sse_encode_overflow_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiBatchDotProductI64ConstMeta,
+        argValues: [a, b, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBatchDotProductI64ConstMeta =>
+      const TaskConstMeta(
+        debugName: "dot_product_i64",
+        argNames: ["a", "b", "policy"],
+      );
+
+  @override
+  Future<Float64List> crateApiBatchPrefixSumsF64({
+    required List<double> values,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_f_64_loose(values, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 5,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_f_64_strict,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBatchPrefixSumsF64ConstMeta,
+        argValues: [values],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBatchPrefixSumsF64ConstMeta =>
+      const TaskConstMeta(debugName: "prefix_sums_f64", argNames: ["values"]);
+
+  @override
+  Future<Int64List> crateApiBatchPrefixSumsI64({
+    required Int64List values,
+    required OverflowPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_i_64_loose(values, serializer);
+          sse_encode_overflow_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_i_64_strict,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiBatchPrefixSumsI64ConstMeta,
+        argValues: [values, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBatchPrefixSumsI64ConstMeta =>
+      const TaskConstMeta(
+        debugName: "prefix_sums_i64",
+        argNames: ["values", "policy"],
+      );
+
+  @override
+  Future<double> crateApiBatchReduceF64({
+    required List<double> values,
+    required Reduction reduction,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_f_64_loose(values, serializer);
+          sse_encode_reduction(reduction, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 7,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiBatchReduceF64ConstMeta,
+        argValues: [values, reduction],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBatchReduceF64ConstMeta =>
+      const TaskConstMeta(
+        debugName: "reduce_f64",
+        argNames: ["values", "reduction"],
+      );
+
+  @override
+  Future<PlatformInt64> crateApiBatchReduceI64({
+    required Int64List values,
+    required Reduction reduction,
+    required OverflowPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_i_64_loose(values, serializer);
+          sse_encode_reduction(reduction, serializer);
+          sse_encode_overflow_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiBatchReduceI64ConstMeta,
+        argValues: [values, reduction, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBatchReduceI64ConstMeta =>
+      const TaskConstMeta(
+        debugName: "reduce_i64",
+        argNames: ["values", "reduction", "policy"],
+      );
+
+  @override
+  BigInt crateApiBufferNativeBufferAddress({required NativeBuffer that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBufferNativeBufferAddressConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBufferNativeBufferAddressConstMeta =>
+      const TaskConstMeta(
+        debugName: "NativeBuffer_address",
+        argNames: ["that"],
+      );
+
+  @override
+  bool crateApiBufferNativeBufferIsEmpty({required NativeBuffer that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBufferNativeBufferIsEmptyConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBufferNativeBufferIsEmptyConstMeta =>
+      const TaskConstMeta(
+        debugName: "NativeBuffer_is_empty",
+        argNames: ["that"],
+      );
+
+  @override
+  BigInt crateApiBufferNativeBufferLen({required NativeBuffer that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBufferNativeBufferLenConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBufferNativeBufferLenConstMeta =>
+      const TaskConstMeta(debugName: "NativeBuffer_len", argNames: ["that"]);
+
+  @override
+  Future<NativeBuffer> crateApiBufferNativeBufferNew({required BigInt len}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_usize(len, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiBufferNativeBufferNewConstMeta,
+        argValues: [len],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBufferNativeBufferNewConstMeta =>
+      const TaskConstMeta(debugName: "NativeBuffer_new", argNames: ["len"]);
+
+  @override
+  Future<int> crateApiBufferChecksumBuffer({required NativeBuffer buffer}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(buffer, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBufferChecksumBufferConstMeta,
+        argValues: [buffer],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBufferChecksumBufferConstMeta =>
+      const TaskConstMeta(debugName: "checksum_buffer", argNames: ["buffer"]);
+
+  @override
+  Future<int> crateApiBufferChecksumBytes({required List<int> data}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBufferChecksumBytesConstMeta,
+        argValues: [data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBufferChecksumBytesConstMeta =>
+      const TaskConstMeta(debugName: "checksum_bytes", argNames: ["data"]);
+
+  @override
+  Future<void> crateApiBufferXorBuffer({
+    required NativeBuffer buffer,
+    required int key,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(buffer, serializer);
+          sse_encode_u_8(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBufferXorBufferConstMeta,
+        argValues: [buffer, key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBufferXorBufferConstMeta =>
+      const TaskConstMeta(debugName: "xor_buffer", argNames: ["buffer", "key"]);
+
+  @override
+  Future<Uint8List> crateApiBufferXorBytes({
+    required List<int> data,
+    required int key,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_u_8(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBufferXorBytesConstMeta,
+        argValues: [data, key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBufferXorBytesConstMeta =>
+      const TaskConstMeta(debugName: "xor_bytes", argNames: ["data", "key"]);
+
+  @override
+  void crateApiCancellationCancellationTokenCancel({
+    required CancellationToken that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCancellationCancellationTokenCancelConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCancellationCancellationTokenCancelConstMeta =>
+      const TaskConstMeta(
+        debugName: "CancellationToken_cancel",
+        argNames: ["that"],
+      );
+
+  @override
+  bool crateApiCancellationCancellationTokenIsCancelled({
+    required CancellationToken that,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCancellationCancellationTokenIsCancelledConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCancellationCancellationTokenIsCancelledConstMeta =>
+      const TaskConstMeta(
+        debugName: "CancellationToken_is_cancelled",
+        argNames: ["that"],
+      );
+
+  @override
+  CancellationToken crateApiCancellationCancellationTokenNew() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiCancellationCancellationTokenNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCancellationCancellationTokenNewConstMeta =>
+      const TaskConstMeta(debugName: "CancellationToken_new", argNames: []);
+
+  @override
+  Future<String> crateApiDispatchInvoke({
+    required String name,
+    required String argsJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_String(argsJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiDispatchInvokeConstMeta,
+        argValues: [name, argsJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDispatchInvokeConstMeta =>
+      const TaskConstMeta(debugName: "invoke", argNames: ["name", "argsJson"]);
+
+  @override
+  List<FunctionSchema> crateApiDispatchListFunctions() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_function_schema,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDispatchListFunctionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDispatchListFunctionsConstMeta =>
+      const TaskConstMeta(debugName: "list_functions", argNames: []);
+
+  @override
+  int crateApiErrorPluginErrorCode({required PluginError that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_plugin_error(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiErrorPluginErrorCodeConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiErrorPluginErrorCodeConstMeta =>
+      const TaskConstMeta(debugName: "PluginError_code", argNames: ["that"]);
+
+  @override
+  String crateApiErrorPluginErrorMessage({required PluginError that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_plugin_error(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiErrorPluginErrorMessageConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiErrorPluginErrorMessageConstMeta =>
+      const TaskConstMeta(debugName: "PluginError_message", argNames: ["that"]);
+
+  @override
+  String crateApiInfoApiSchema() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInfoApiSchemaConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInfoApiSchemaConstMeta =>
+      const TaskConstMeta(debugName: "api_schema", argNames: []);
+
+  @override
+  BuildInfo crateApiInfoBuildInfo() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_build_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInfoBuildInfoConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInfoBuildInfoConstMeta =>
+      const TaskConstMeta(debugName: "build_info", argNames: []);
+
+  @override
+  Future<void> crateApiInitInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<void> crateApiInitInitPlugin({required PluginConfig config}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_plugin_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiInitInitPluginConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitInitPluginConstMeta =>
+      const TaskConstMeta(debugName: "init_plugin", argNames: ["config"]);
+
+  @override
+  PluginConfig? crateApiInitPluginConfig() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_plugin_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitPluginConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitPluginConfigConstMeta =>
+      const TaskConstMeta(debugName: "plugin_config", argNames: []);
+
+  @override
+  Future<void> crateApiLocaleAddTranslations({
+    required String locale,
+    required String ftl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(locale, serializer);
+          sse_encode_String(ftl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiLocaleAddTranslationsConstMeta,
+        argValues: [locale, ftl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLocaleAddTranslationsConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_translations",
+        argNames: ["locale", "ftl"],
+      );
+
+  @override
+  LocaleInfo crateApiLocaleNegotiateLocale({required String requested}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(requested, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_locale_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLocaleNegotiateLocaleConstMeta,
+        argValues: [requested],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLocaleNegotiateLocaleConstMeta =>
+      const TaskConstMeta(
+        debugName: "negotiate_locale",
+        argNames: ["requested"],
+      );
+
+  @override
+  List<String> crateApiLocaleSupportedLocales() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLocaleSupportedLocalesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLocaleSupportedLocalesConstMeta =>
+      const TaskConstMeta(debugName: "supported_locales", argNames: []);
+
+  @override
+  void crateApiLoggingClearLogTargetLevels() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggingClearLogTargetLevelsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggingClearLogTargetLevelsConstMeta =>
+      const TaskConstMeta(debugName: "clear_log_target_levels", argNames: []);
+
+  @override
+  void crateApiLoggingSetLogLevel({required LogLevel level}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_log_level(level, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggingSetLogLevelConstMeta,
+        argValues: [level],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggingSetLogLevelConstMeta =>
+      const TaskConstMeta(debugName: "set_log_level", argNames: ["level"]);
+
+  @override
+  void crateApiLoggingSetLogTargetLevel({
+    required String target,
+    required LogLevel level,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(target, serializer);
+          sse_encode_log_level(level, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiLoggingSetLogTargetLevelConstMeta,
+        argValues: [target, level],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoggingSetLogTargetLevelConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_log_target_level",
+        argNames: ["target", "level"],
+      );
+
+  @override
+  Stream<LogRecord> crateApiLoggingSubscribeLogs() {
+    final sink = RustStreamSink<LogRecord>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_log_record_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 35,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiLoggingSubscribeLogsConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiLoggingSubscribeLogsConstMeta =>
+      const TaskConstMeta(debugName: "subscribe_logs", argNames: ["sink"]);
+
+  @override
+  ProgressReporter crateApiProgressProgressReporterNew({
+    required int minIntervalMs,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(minIntervalMs, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiProgressProgressReporterNewConstMeta,
+        argValues: [minIntervalMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiProgressProgressReporterNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "ProgressReporter_new",
+        argNames: ["minIntervalMs"],
+      );
+
+  @override
+  Stream<ProgressUpdate> crateApiProgressProgressReporterUpdates({
+    required ProgressReporter that,
+  }) {
+    final sink = RustStreamSink<ProgressUpdate>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(that, serializer);
+            sse_encode_StreamSink_progress_update_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 37,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiProgressProgressReporterUpdatesConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiProgressProgressReporterUpdatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "ProgressReporter_updates",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  Future<PlatformInt64> crateApiSimpleAddNumbers({
+    required PlatformInt64 a,
+    required PlatformInt64 b,
+    required OverflowPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(a, serializer);
+          sse_encode_i_64(b, serializer);
+          sse_encode_overflow_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiSimpleAddNumbersConstMeta,
+        argValues: [a, b, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleAddNumbersConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_numbers",
+        argNames: ["a", "b", "policy"],
+      );
+
+  @override
+  PlatformInt64 crateApiSimpleAddNumbersSync({
+    required PlatformInt64 a,
+    required PlatformInt64 b,
+    required OverflowPolicy policy,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(a, serializer);
+          sse_encode_i_64(b, serializer);
+          sse_encode_overflow_policy(policy, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiSimpleAddNumbersSyncConstMeta,
+        argValues: [a, b, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleAddNumbersSyncConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_numbers_sync",
+        argNames: ["a", "b", "policy"],
+      );
+
+  @override
+  Future<BigInt> crateApiSimpleCalculateFibonacci({required int n}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(n, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiSimpleCalculateFibonacciConstMeta,
+        argValues: [n],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleCalculateFibonacciConstMeta =>
+      const TaskConstMeta(debugName: "calculate_fibonacci", argNames: ["n"]);
+
+  @override
+  Future<String> crateApiSimpleCalculateFibonacciBig({
+    required int n,
+    BigInt? modulus,
+    required CancellationToken cancellation,
+    required ProgressReporter progress,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(n, serializer);
+          sse_encode_opt_box_autoadd_u_64(modulus, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(cancellation, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(progress, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiSimpleCalculateFibonacciBigConstMeta,
+        argValues: [n, modulus, cancellation, progress],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleCalculateFibonacciBigConstMeta =>
+      const TaskConstMeta(
+        debugName: "calculate_fibonacci_big",
+        argNames: ["n", "modulus", "cancellation", "progress"],
+      );
+
+  @override
+  BigInt crateApiSimpleCalculateFibonacciSync({required int n}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(n, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiSimpleCalculateFibonacciSyncConstMeta,
+        argValues: [n],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleCalculateFibonacciSyncConstMeta =>
+      const TaskConstMeta(
+        debugName: "calculate_fibonacci_sync",
+        argNames: ["n"],
+      );
+
+  @override
+  Future<PlatformInt64> crateApiSimpleDivideNumbers({
+    required PlatformInt64 a,
+    required PlatformInt64 b,
+    required OverflowPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(a, serializer);
+          sse_encode_i_64(b, serializer);
+          sse_encode_overflow_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiSimpleDivideNumbersConstMeta,
+        argValues: [a, b, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleDivideNumbersConstMeta =>
+      const TaskConstMeta(
+        debugName: "divide_numbers",
+        argNames: ["a", "b", "policy"],
+      );
+
+  @override
+  PlatformInt64 crateApiSimpleDivideNumbersSync({
+    required PlatformInt64 a,
+    required PlatformInt64 b,
+    required OverflowPolicy policy,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(a, serializer);
+          sse_encode_i_64(b, serializer);
+          sse_encode_overflow_policy(policy, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiSimpleDivideNumbersSyncConstMeta,
+        argValues: [a, b, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleDivideNumbersSyncConstMeta =>
+      const TaskConstMeta(
+        debugName: "divide_numbers_sync",
+        argNames: ["a", "b", "policy"],
+      );
+
+  @override
+  Stream<FibonacciChunk> crateApiSimpleFibonacciSequence({
+    required int start,
+    required int count,
+    required int chunkSize,
+    required CancellationToken cancellation,
+  }) {
+    final sink = RustStreamSink<FibonacciChunk>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_fibonacci_chunk_Sse(sink, serializer);
+            sse_encode_u_32(start, serializer);
+            sse_encode_u_32(count, serializer);
+            sse_encode_u_32(chunkSize, serializer);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(cancellation, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 45,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_plugin_error,
+          ),
+          constMeta: kCrateApiSimpleFibonacciSequenceConstMeta,
+          argValues: [sink, start, count, chunkSize, cancellation],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiSimpleFibonacciSequenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "fibonacci_sequence",
+        argNames: ["sink", "start", "count", "chunkSize", "cancellation"],
+      );
+
+  @override
+  Future<String> crateApiSimpleGreet({
+    required String name,
+    String? locale,
+    Gender? gender,
+    String? template,
+    GreetingStyle? style,
+    String? timeZone,
+    PlatformInt64? nowUtcMillis,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_opt_String(locale, serializer);
+          sse_encode_opt_box_autoadd_gender(gender, serializer);
+          sse_encode_opt_String(template, serializer);
+          sse_encode_opt_box_autoadd_greeting_style(style, serializer);
+          sse_encode_opt_String(timeZone, serializer);
+          sse_encode_opt_box_autoadd_i_64(nowUtcMillis, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name, locale, gender, template, style, timeZone, nowUtcMillis],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(
+        debugName: "greet",
+        argNames: ["name", "locale", "gender", "template", "style", "timeZone", "nowUtcMillis"],
+      );
+
+  @override
+  String crateApiSimpleGreetSync({
+    required String name,
+    String? locale,
+    Gender? gender,
+    GreetingStyle? style,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_opt_String(locale, serializer);
+          sse_encode_opt_box_autoadd_gender(gender, serializer);
+          sse_encode_opt_box_autoadd_greeting_style(style, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiSimpleGreetSyncConstMeta,
+        argValues: [name, locale, gender, style],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGreetSyncConstMeta =>
+      const TaskConstMeta(
+        debugName: "greet_sync",
+        argNames: ["name", "locale", "gender", "style"],
+      );
+
+  @override
+  Future<PlatformInt64> crateApiSimpleMultiplyNumbers({
+    required PlatformInt64 a,
+    required PlatformInt64 b,
+    required OverflowPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(a, serializer);
+          sse_encode_i_64(b, serializer);
+          sse_encode_overflow_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiSimpleMultiplyNumbersConstMeta,
+        argValues: [a, b, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMultiplyNumbersConstMeta =>
+      const TaskConstMeta(
+        debugName: "multiply_numbers",
+        argNames: ["a", "b", "policy"],
+      );
+
+  @override
+  PlatformInt64 crateApiSimpleMultiplyNumbersSync({
+    required PlatformInt64 a,
+    required PlatformInt64 b,
+    required OverflowPolicy policy,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(a, serializer);
+          sse_encode_i_64(b, serializer);
+          sse_encode_overflow_policy(policy, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiSimpleMultiplyNumbersSyncConstMeta,
+        argValues: [a, b, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleMultiplyNumbersSyncConstMeta =>
+      const TaskConstMeta(
+        debugName: "multiply_numbers_sync",
+        argNames: ["a", "b", "policy"],
+      );
+
+  @override
+  Future<PlatformInt64> crateApiSimplePowNumber({
+    required PlatformInt64 base,
+    required int exponent,
+    required OverflowPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(base, serializer);
+          sse_encode_u_32(exponent, serializer);
+          sse_encode_overflow_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiSimplePowNumberConstMeta,
+        argValues: [base, exponent, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimplePowNumberConstMeta =>
+      const TaskConstMeta(
+        debugName: "pow_number",
+        argNames: ["base", "exponent", "policy"],
+      );
+
+  @override
+  PlatformInt64 crateApiSimplePowNumberSync({
+    required PlatformInt64 base,
+    required int exponent,
+    required OverflowPolicy policy,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(base, serializer);
+          sse_encode_u_32(exponent, serializer);
+          sse_encode_overflow_policy(policy, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiSimplePowNumberSyncConstMeta,
+        argValues: [base, exponent, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimplePowNumberSyncConstMeta =>
+      const TaskConstMeta(
+        debugName: "pow_number_sync",
+        argNames: ["base", "exponent", "policy"],
+      );
+
+  @override
+  Future<ProcessedName> crateApiSimpleProcessName({
+    required String name,
+    int? maxGraphemes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_opt_box_autoadd_u_32(maxGraphemes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_processed_name,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiSimpleProcessNameConstMeta,
+        argValues: [name, maxGraphemes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleProcessNameConstMeta =>
+      const TaskConstMeta(
+        debugName: "process_name",
+        argNames: ["name", "maxGraphemes"],
+      );
+
+  @override
+  Future<PlatformInt64> crateApiSimpleSubtractNumbers({
+    required PlatformInt64 a,
+    required PlatformInt64 b,
+    required OverflowPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(a, serializer);
+          sse_encode_i_64(b, serializer);
+          sse_encode_overflow_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiSimpleSubtractNumbersConstMeta,
+        argValues: [a, b, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSubtractNumbersConstMeta =>
+      const TaskConstMeta(
+        debugName: "subtract_numbers",
+        argNames: ["a", "b", "policy"],
+      );
+
+  @override
+  PlatformInt64 crateApiSimpleSubtractNumbersSync({
+    required PlatformInt64 a,
+    required PlatformInt64 b,
+    required OverflowPolicy policy,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(a, serializer);
+          sse_encode_i_64(b, serializer);
+          sse_encode_overflow_policy(policy, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiSimpleSubtractNumbersSyncConstMeta,
+        argValues: [a, b, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleSubtractNumbersSyncConstMeta =>
+      const TaskConstMeta(
+        debugName: "subtract_numbers_sync",
+        argNames: ["a", "b", "policy"],
+      );
+
+  @override
+  EmojiSupport crateApiStyleEmojiSupport({
+    required String emoji,
+    String? supportedVersion,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(emoji, serializer);
+          sse_encode_opt_String(supportedVersion, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_emoji_support,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiStyleEmojiSupportConstMeta,
+        argValues: [emoji, supportedVersion],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStyleEmojiSupportConstMeta =>
+      const TaskConstMeta(
+        debugName: "emoji_support",
+        argNames: ["emoji", "supportedVersion"],
+      );
+
+  @override
+  List<String> crateApiTemplatesGreetingTemplates() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTemplatesGreetingTemplatesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTemplatesGreetingTemplatesConstMeta =>
+      const TaskConstMeta(debugName: "greeting_templates", argNames: []);
+
+  @override
+  Future<void> crateApiTemplatesRegisterGreetingTemplate({
+    required String name,
+    required String source,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_String(source, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiTemplatesRegisterGreetingTemplateConstMeta,
+        argValues: [name, source],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTemplatesRegisterGreetingTemplateConstMeta =>
+      const TaskConstMeta(
+        debugName: "register_greeting_template",
+        argNames: ["name", "source"],
+      );
+
+  @override
+  bool crateApiTemplatesUnregisterGreetingTemplate({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTemplatesUnregisterGreetingTemplateConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTemplatesUnregisterGreetingTemplateConstMeta =>
+      const TaskConstMeta(
+        debugName: "unregister_greeting_template",
+        argNames: ["name"],
+      );
+
+  @override
+  TimeOfDay crateApiTimeTimeOfDay({
+    required String timeZone,
+    PlatformInt64? nowUtcMillis,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(timeZone, serializer);
+          sse_encode_opt_box_autoadd_i_64(nowUtcMillis, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_time_of_day,
+          decodeErrorData: sse_decode_plugin_error,
+        ),
+        constMeta: kCrateApiTimeTimeOfDayConstMeta,
+        argValues: [timeZone, nowUtcMillis],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTimeTimeOfDayConstMeta =>
+      const TaskConstMeta(
+        debugName: "time_of_day",
+        argNames: ["timeZone", "nowUtcMillis"],
+      );
+
+  @override
+  void crateApiWorkersSetNextCallPriority({TaskPriority? priority}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_task_priority(priority, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkersSetNextCallPriorityConstMeta,
+        argValues: [priority],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkersSetNextCallPriorityConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_next_call_priority",
+        argNames: ["priority"],
+      );
+
+  @override
+  void crateApiWorkersSetTaskRoute({
+    required String function,
+    required WorkerPoolKind pool,
+    required TaskPriority priority,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(function, serializer);
+          sse_encode_worker_pool_kind(pool, serializer);
+          sse_encode_task_priority(priority, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkersSetTaskRouteConstMeta,
+        argValues: [function, pool, priority],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkersSetTaskRouteConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_task_route",
+        argNames: ["function", "pool", "priority"],
+      );
+
+  @override
+  List<WorkerPoolMetrics> crateApiWorkersWorkerPoolMetrics() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_worker_pool_metrics,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWorkersWorkerPoolMetricsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWorkersWorkerPoolMetricsConstMeta =>
+      const TaskConstMeta(debugName: "worker_pool_metrics", argNames: []);
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_CancellationToken =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_CancellationToken =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_NativeBuffer =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_NativeBuffer =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ProgressReporter =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ProgressReporter =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  CancellationToken dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CancellationTokenImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  NativeBuffer dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NativeBufferImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ProgressReporter dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ProgressReporterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  NativeBuffer dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NativeBufferImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  CancellationToken dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CancellationTokenImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  NativeBuffer dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NativeBufferImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ProgressReporter dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ProgressReporterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  CancellationToken dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CancellationTokenImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  NativeBuffer dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NativeBufferImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ProgressReporter dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ProgressReporterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<FibonacciChunk> dco_decode_StreamSink_fibonacci_chunk_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<LogRecord> dco_decode_StreamSink_log_record_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ProgressUpdate> dco_decode_StreamSink_progress_update_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  BatchOp dco_decode_batch_op(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BatchOp.values[raw as int];
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  EmojiSet dco_decode_box_autoadd_emoji_set(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_emoji_set(raw);
+  }
+
+  @protected
+  Gender dco_decode_box_autoadd_gender(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_gender(raw);
+  }
+
+  @protected
+  GreetingStyle dco_decode_box_autoadd_greeting_style(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_greeting_style(raw);
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  PluginConfig dco_decode_box_autoadd_plugin_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_plugin_config(raw);
+  }
+
+  @protected
+  PluginError dco_decode_box_autoadd_plugin_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_plugin_error(raw);
+  }
+
+  @protected
+  TaskPriority dco_decode_box_autoadd_task_priority(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_task_priority(raw);
+  }
+
+  @protected
+  TextMode dco_decode_box_autoadd_text_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_text_mode(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  BuildInfo dco_decode_build_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return BuildInfo(
+      crateVersion: dco_decode_String(arr[0]),
+      gitCommit: dco_decode_String(arr[1]),
+      rustcVersion: dco_decode_String(arr[2]),
+      target: dco_decode_String(arr[3]),
+      features: dco_decode_list_String(arr[4]),
+      profile: dco_decode_String(arr[5]),
+      frbCodegenVersion: dco_decode_String(arr[6]),
+      frbContentHash: dco_decode_i_32(arr[7]),
+      tzdbVersion: dco_decode_String(arr[8]),
+    );
+  }
+
+  @protected
+  EmojiSet dco_decode_emoji_set(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EmojiSet.values[raw as int];
+  }
+
+  @protected
+  EmojiSupport dco_decode_emoji_support(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return EmojiSupport(
+      name: dco_decode_opt_String(arr[0]),
+      emojiVersion: dco_decode_opt_String(arr[1]),
+      likelyRenderable: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FibonacciChunk dco_decode_fibonacci_chunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FibonacciChunk(
+      start: dco_decode_u_32(arr[0]),
+      terms: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  FunctionSchema dco_decode_function_schema(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FunctionSchema(
+      name: dco_decode_String(arr[0]),
+      params: dco_decode_list_param_schema(arr[1]),
+      returns: dco_decode_String(arr[2]),
+      stream: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  Gender dco_decode_gender(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Gender.values[raw as int];
+  }
+
+  @protected
+  GreetingStyle dco_decode_greeting_style(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return GreetingStyle(
+      emoji: dco_decode_opt_box_autoadd_emoji_set(arr[0]),
+      mode: dco_decode_opt_box_autoadd_text_mode(arr[1]),
+      prefix: dco_decode_opt_String(arr[2]),
+      suffix: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<FunctionSchema> dco_decode_list_function_schema(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_function_schema).toList();
+  }
+
+  @protected
+  List<LogField> dco_decode_list_log_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_log_field).toList();
+  }
+
+  @protected
+  List<NameWarning> dco_decode_list_name_warning(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_name_warning).toList();
+  }
+
+  @protected
+  List<ParamSchema> dco_decode_list_param_schema(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_param_schema).toList();
+  }
+
+  @protected
+  List<double> dco_decode_list_prim_f_64_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<double>;
+  }
+
+  @protected
+  Float64List dco_decode_list_prim_f_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float64List;
+  }
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeInt64List(raw);
+  }
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeInt64List(raw);
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<WorkerPoolMetrics> dco_decode_list_worker_pool_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_worker_pool_metrics).toList();
+  }
+
+  @protected
+  LocaleInfo dco_decode_locale_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LocaleInfo(
+      locale: dco_decode_String(arr[0]),
+      fallbacks: dco_decode_list_String(arr[1]),
+      direction: dco_decode_writing_direction(arr[2]),
+    );
+  }
+
+  @protected
+  LogField dco_decode_log_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LogField(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  LogLevel dco_decode_log_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LogLevel.values[raw as int];
+  }
+
+  @protected
+  LogRecord dco_decode_log_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LogRecord(
+      level: dco_decode_log_level(arr[0]),
+      target: dco_decode_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      timestampMillis: dco_decode_i_64(arr[3]),
+      fields: dco_decode_list_log_field(arr[4]),
+    );
+  }
+
+  @protected
+  NameWarning dco_decode_name_warning(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return NameWarning_Normalized();
+      case 1:
+        return NameWarning_RemovedCharacters(
+          codePoints: dco_decode_list_prim_u_32_strict(raw[1]),
+        );
+      case 2:
+        return NameWarning_MixedScript(
+          word: dco_decode_String(raw[1]),
+          scripts: dco_decode_list_String(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  EmojiSet? dco_decode_opt_box_autoadd_emoji_set(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_emoji_set(raw);
+  }
+
+  @protected
+  Gender? dco_decode_opt_box_autoadd_gender(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_gender(raw);
+  }
+
+  @protected
+  GreetingStyle? dco_decode_opt_box_autoadd_greeting_style(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_greeting_style(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  PluginConfig? dco_decode_opt_box_autoadd_plugin_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_plugin_config(raw);
+  }
+
+  @protected
+  TaskPriority? dco_decode_opt_box_autoadd_task_priority(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_task_priority(raw);
+  }
+
+  @protected
+  TextMode? dco_decode_opt_box_autoadd_text_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_text_mode(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  OverflowPolicy dco_decode_overflow_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return OverflowPolicy.values[raw as int];
+  }
+
+  @protected
+  ParamSchema dco_decode_param_schema(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ParamSchema(
+      name: dco_decode_String(arr[0]),
+      typeName: dco_decode_String(arr[1]),
+      optional: dco_decode_bool(arr[2]),
+      values: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  PluginConfig dco_decode_plugin_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PluginConfig(
+      logLevel: dco_decode_log_level(arr[0]),
+      workerThreads: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      blockingThreads: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      dataDirectory: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  PluginError dco_decode_plugin_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return PluginError_Overflow(operation: dco_decode_String(raw[1]));
+      case 1:
+        return PluginError_InvalidArgument(
+          name: dco_decode_String(raw[1]),
+          reason: dco_decode_String(raw[2]),
+        );
+      case 2:
+        return PluginError_Cancelled();
+      case 3:
+        return PluginError_Internal(details: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ProcessedName dco_decode_processed_name(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ProcessedName(
+      name: dco_decode_String(arr[0]),
+      graphemes: dco_decode_u_32(arr[1]),
+      scripts: dco_decode_list_String(arr[2]),
+      warnings: dco_decode_list_name_warning(arr[3]),
+    );
+  }
+
+  @protected
+  ProgressUpdate dco_decode_progress_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ProgressUpdate(
+      fraction: dco_decode_f_64(arr[0]),
+      message: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  Reduction dco_decode_reduction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Reduction.values[raw as int];
+  }
+
+  @protected
+  TaskPriority dco_decode_task_priority(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TaskPriority.values[raw as int];
+  }
+
+  @protected
+  TextMode dco_decode_text_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TextMode.values[raw as int];
+  }
+
+  @protected
+  TimeOfDay dco_decode_time_of_day(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TimeOfDay.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  WorkerPoolKind dco_decode_worker_pool_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WorkerPoolKind.values[raw as int];
+  }
+
+  @protected
+  WorkerPoolMetrics dco_decode_worker_pool_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return WorkerPoolMetrics(
+      pool: dco_decode_worker_pool_kind(arr[0]),
+      threads: dco_decode_u_32(arr[1]),
+      busy: dco_decode_u_32(arr[2]),
+      queued: dco_decode_u_32(arr[3]),
+      completed: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  WritingDirection dco_decode_writing_direction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WritingDirection.values[raw as int];
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  CancellationToken sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(deserializer);
+    return inner;
+  }
+
+  @protected
+  NativeBuffer sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(deserializer);
+    return inner;
+  }
+
+  @protected
+  ProgressReporter sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(deserializer);
+    return inner;
+  }
+
+  @protected
+  NativeBuffer sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(deserializer);
+    return inner;
+  }
+
+  @protected
+  CancellationToken sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(deserializer);
+    return inner;
+  }
+
+  @protected
+  NativeBuffer sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(deserializer);
+    return inner;
+  }
+
+  @protected
+  ProgressReporter sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(deserializer);
+    return inner;
+  }
+
+  @protected
+  CancellationToken sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return CancellationTokenImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  NativeBuffer sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return NativeBufferImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  ProgressReporter sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ProgressReporterImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RustStreamSink<FibonacciChunk> sse_decode_StreamSink_fibonacci_chunk_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<LogRecord> sse_decode_StreamSink_log_record_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ProgressUpdate> sse_decode_StreamSink_progress_update_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  BatchOp sse_decode_batch_op(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BatchOp.values[inner];
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  EmojiSet sse_decode_box_autoadd_emoji_set(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_emoji_set(deserializer));
+  }
+
+  @protected
+  Gender sse_decode_box_autoadd_gender(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_gender(deserializer));
+  }
+
+  @protected
+  GreetingStyle sse_decode_box_autoadd_greeting_style(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_greeting_style(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  PluginConfig sse_decode_box_autoadd_plugin_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_plugin_config(deserializer));
+  }
+
+  @protected
+  PluginError sse_decode_box_autoadd_plugin_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_plugin_error(deserializer));
+  }
+
+  @protected
+  TaskPriority sse_decode_box_autoadd_task_priority(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_task_priority(deserializer));
+  }
+
+  @protected
+  TextMode sse_decode_box_autoadd_text_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_text_mode(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  BuildInfo sse_decode_build_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_crateVersion = sse_decode_String(deserializer);
+    var var_gitCommit = sse_decode_String(deserializer);
+    var var_rustcVersion = sse_decode_String(deserializer);
+    var var_target = sse_decode_String(deserializer);
+    var var_features = sse_decode_list_String(deserializer);
+    var var_profile = sse_decode_String(deserializer);
+    var var_frbCodegenVersion = sse_decode_String(deserializer);
+    var var_frbContentHash = sse_decode_i_32(deserializer);
+    var var_tzdbVersion = sse_decode_String(deserializer);
+    return BuildInfo(
+      crateVersion: var_crateVersion,
+      gitCommit: var_gitCommit,
+      rustcVersion: var_rustcVersion,
+      target: var_target,
+      features: var_features,
+      profile: var_profile,
+      frbCodegenVersion: var_frbCodegenVersion,
+      frbContentHash: var_frbContentHash,
+      tzdbVersion: var_tzdbVersion,
+    );
+  }
+
+  @protected
+  EmojiSet sse_decode_emoji_set(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EmojiSet.values[inner];
+  }
+
+  @protected
+  EmojiSupport sse_decode_emoji_support(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_emojiVersion = sse_decode_opt_String(deserializer);
+    var var_likelyRenderable = sse_decode_bool(deserializer);
+    return EmojiSupport(
+      name: var_name,
+      emojiVersion: var_emojiVersion,
+      likelyRenderable: var_likelyRenderable,
+    );
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FibonacciChunk sse_decode_fibonacci_chunk(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_start = sse_decode_u_32(deserializer);
+    var var_terms = sse_decode_list_String(deserializer);
+    return FibonacciChunk(start: var_start, terms: var_terms);
+  }
+
+  @protected
+  FunctionSchema sse_decode_function_schema(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_params = sse_decode_list_param_schema(deserializer);
+    var var_returns = sse_decode_String(deserializer);
+    var var_stream = sse_decode_opt_String(deserializer);
+    return FunctionSchema(
+      name: var_name,
+      params: var_params,
+      returns: var_returns,
+      stream: var_stream,
+    );
+  }
+
+  @protected
+  Gender sse_decode_gender(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Gender.values[inner];
+  }
+
+  @protected
+  GreetingStyle sse_decode_greeting_style(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_emoji = sse_decode_opt_box_autoadd_emoji_set(deserializer);
+    var var_mode = sse_decode_opt_box_autoadd_text_mode(deserializer);
+    var var_prefix = sse_decode_opt_String(deserializer);
+    var var_suffix = sse_decode_opt_String(deserializer);
+    return GreetingStyle(
+      emoji: var_emoji,
+      mode: var_mode,
+      prefix: var_prefix,
+      suffix: var_suffix,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FunctionSchema> sse_decode_list_function_schema(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FunctionSchema>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_function_schema(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LogField> sse_decode_list_log_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LogField>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_log_field(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NameWarning> sse_decode_list_name_warning(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NameWarning>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_name_warning(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ParamSchema> sse_decode_list_param_schema(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ParamSchema>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_param_schema(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<double> sse_decode_list_prim_f_64_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat64List(len_);
+  }
+
+  @protected
+  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat64List(len_);
+  }
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt64List(len_);
+  }
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt64List(len_);
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<WorkerPoolMetrics> sse_decode_list_worker_pool_metrics(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WorkerPoolMetrics>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_worker_pool_metrics(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LocaleInfo sse_decode_locale_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_locale = sse_decode_String(deserializer);
+    var var_fallbacks = sse_decode_list_String(deserializer);
+    var var_direction = sse_decode_writing_direction(deserializer);
+    return LocaleInfo(
+      locale: var_locale,
+      fallbacks: var_fallbacks,
+      direction: var_direction,
+    );
+  }
+
+  @protected
+  LogField sse_decode_log_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return LogField(key: var_key, value: var_value);
+  }
+
+  @protected
+  LogLevel sse_decode_log_level(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LogLevel.values[inner];
+  }
+
+  @protected
+  LogRecord sse_decode_log_record(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_level = sse_decode_log_level(deserializer);
+    var var_target = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_timestampMillis = sse_decode_i_64(deserializer);
+    var var_fields = sse_decode_list_log_field(deserializer);
+    return LogRecord(
+      level: var_level,
+      target: var_target,
+      message: var_message,
+      timestampMillis: var_timestampMillis,
+      fields: var_fields,
+    );
+  }
+
+  @protected
+  NameWarning sse_decode_name_warning(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return NameWarning_Normalized();
+      case 1:
+        var var_codePoints = sse_decode_list_prim_u_32_strict(deserializer);
+        return NameWarning_RemovedCharacters(codePoints: var_codePoints);
+      case 2:
+        var var_word = sse_decode_String(deserializer);
+        var var_scripts = sse_decode_list_String(deserializer);
+        return NameWarning_MixedScript(word: var_word, scripts: var_scripts);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  EmojiSet? sse_decode_opt_box_autoadd_emoji_set(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_emoji_set(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Gender? sse_decode_opt_box_autoadd_gender(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_gender(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  GreetingStyle? sse_decode_opt_box_autoadd_greeting_style(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_greeting_style(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PluginConfig? sse_decode_opt_box_autoadd_plugin_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_plugin_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  TaskPriority? sse_decode_opt_box_autoadd_task_priority(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_task_priority(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  TextMode? sse_decode_opt_box_autoadd_text_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_text_mode(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OverflowPolicy sse_decode_overflow_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return OverflowPolicy.values[inner];
+  }
+
+  @protected
+  ParamSchema sse_decode_param_schema(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_typeName = sse_decode_String(deserializer);
+    var var_optional = sse_decode_bool(deserializer);
+    var var_values = sse_decode_list_String(deserializer);
+    return ParamSchema(
+      name: var_name,
+      typeName: var_typeName,
+      optional: var_optional,
+      values: var_values,
+    );
+  }
+
+  @protected
+  PluginConfig sse_decode_plugin_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_logLevel = sse_decode_log_level(deserializer);
+    var var_workerThreads = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_blockingThreads = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_dataDirectory = sse_decode_opt_String(deserializer);
+    return PluginConfig(
+      logLevel: var_logLevel,
+      workerThreads: var_workerThreads,
+      blockingThreads: var_blockingThreads,
+      dataDirectory: var_dataDirectory,
+    );
+  }
+
+  @protected
+  PluginError sse_decode_plugin_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_operation = sse_decode_String(deserializer);
+        return PluginError_Overflow(operation: var_operation);
+      case 1:
+        var var_name = sse_decode_String(deserializer);
+        var var_reason = sse_decode_String(deserializer);
+        return PluginError_InvalidArgument(name: var_name, reason: var_reason);
+      case 2:
+        return PluginError_Cancelled();
+      case 3:
+        var var_details = sse_decode_String(deserializer);
+        return PluginError_Internal(details: var_details);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ProcessedName sse_decode_processed_name(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_graphemes = sse_decode_u_32(deserializer);
+    var var_scripts = sse_decode_list_String(deserializer);
+    var var_warnings = sse_decode_list_name_warning(deserializer);
+    return ProcessedName(
+      name: var_name,
+      graphemes: var_graphemes,
+      scripts: var_scripts,
+      warnings: var_warnings,
+    );
+  }
+
+  @protected
+  ProgressUpdate sse_decode_progress_update(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fraction = sse_decode_f_64(deserializer);
+    var var_message = sse_decode_opt_String(deserializer);
+    return ProgressUpdate(fraction: var_fraction, message: var_message);
+  }
+
+  @protected
+  Reduction sse_decode_reduction(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Reduction.values[inner];
+  }
+
+  @protected
+  TaskPriority sse_decode_task_priority(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TaskPriority.values[inner];
+  }
+
+  @protected
+  TextMode sse_decode_text_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TextMode.values[inner];
+  }
+
+  @protected
+  TimeOfDay sse_decode_time_of_day(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TimeOfDay.values[inner];
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  WorkerPoolKind sse_decode_worker_pool_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WorkerPoolKind.values[inner];
+  }
+
+  @protected
+  WorkerPoolMetrics sse_decode_worker_pool_metrics(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pool = sse_decode_worker_pool_kind(deserializer);
+    var var_threads = sse_decode_u_32(deserializer);
+    var var_busy = sse_decode_u_32(deserializer);
+    var var_queued = sse_decode_u_32(deserializer);
+    var var_completed = sse_decode_u_64(deserializer);
+    return WorkerPoolMetrics(
+      pool: var_pool,
+      threads: var_threads,
+      busy: var_busy,
+      queued: var_queued,
+      completed: var_completed,
+    );
+  }
+
+  @protected
+  WritingDirection sse_decode_writing_direction(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WritingDirection.values[inner];
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    CancellationToken self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as CancellationTokenImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    NativeBuffer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as NativeBufferImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    ProgressReporter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ProgressReporterImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    NativeBuffer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as NativeBufferImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    CancellationToken self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as CancellationTokenImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    NativeBuffer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as NativeBufferImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    ProgressReporter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ProgressReporterImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    CancellationToken self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as CancellationTokenImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    NativeBuffer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as NativeBufferImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    ProgressReporter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ProgressReporterImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_fibonacci_chunk_Sse(
+    RustStreamSink<FibonacciChunk> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_fibonacci_chunk,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_log_record_Sse(
+    RustStreamSink<LogRecord> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_log_record,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_progress_update_Sse(
+    RustStreamSink<ProgressUpdate> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_progress_update,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_batch_op(BatchOp self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_emoji_set(
+    EmojiSet self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_emoji_set(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_gender(Gender self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_gender(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_greeting_style(
+    GreetingStyle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_greeting_style(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_plugin_config(
+    PluginConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_plugin_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_plugin_error(
+    PluginError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_plugin_error(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_task_priority(
+    TaskPriority self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_task_priority(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_text_mode(
+    TextMode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_text_mode(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_build_info(BuildInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.crateVersion, serializer);
+    sse_encode_String(self.gitCommit, serializer);
+    sse_encode_String(self.rustcVersion, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_list_String(self.features, serializer);
+    sse_encode_String(self.profile, serializer);
+    sse_encode_String(self.frbCodegenVersion, serializer);
+    sse_encode_i_32(self.frbContentHash, serializer);
+    sse_encode_String(self.tzdbVersion, serializer);
+  }
+
+  @protected
+  void sse_encode_emoji_set(EmojiSet self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_emoji_support(EmojiSupport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.emojiVersion, serializer);
+    sse_encode_bool(self.likelyRenderable, serializer);
+  }
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
+
+  @protected
+  void sse_encode_fibonacci_chunk(
+    FibonacciChunk self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.start, serializer);
+    sse_encode_list_String(self.terms, serializer);
+  }
+
+  @protected
+  void sse_encode_function_schema(
+    FunctionSchema self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_list_param_schema(self.params, serializer);
+    sse_encode_String(self.returns, serializer);
+    sse_encode_opt_String(self.stream, serializer);
+  }
+
+  @protected
+  void sse_encode_gender(Gender self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_greeting_style(GreetingStyle self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_emoji_set(self.emoji, serializer);
+    sse_encode_opt_box_autoadd_text_mode(self.mode, serializer);
+    sse_encode_opt_String(self.prefix, serializer);
+    sse_encode_opt_String(self.suffix, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_function_schema(
+    List<FunctionSchema> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_function_schema(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_log_field(
+    List<LogField> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_log_field(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_name_warning(
+    List<NameWarning> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_name_warning(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_param_schema(
+    List<ParamSchema> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_param_schema(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_f_64_loose(
+    List<double> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat64List(
+      self is Float64List ? self : Float64List.fromList(self),
+    );
+  }
+
+  @protected
+  void sse_encode_list_prim_f_64_strict(
+    Float64List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat64List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_i_64_loose(
+    Int64List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt64List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_i_64_strict(
+    Int64List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt64List(self);
+  }
 
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
+  }
 
-  @override
-  Future<PlatformInt64> crateApiSimpleAddNumbers({
-    required PlatformInt64 a,
-    required PlatformInt64 b,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(a, serializer);
-          sse_encode_i_64(b, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 1,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_i_64,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleAddNumbersConstMeta,
-        argValues: [a, b],
-        apiImpl: this,
-      ),
+  @protected
+  void sse_encode_list_prim_u_8_loose(
+    List<int> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(
+      self is Uint8List ? self : Uint8List.fromList(self),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleAddNumbersConstMeta =>
-      const TaskConstMeta(debugName: "add_numbers", argNames: ["a", "b"]);
-
-  @override
-  Future<BigInt> crateApiSimpleCalculateFibonacci({required int n}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(n, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_u_64,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleCalculateFibonacciConstMeta,
-        argValues: [n],
-        apiImpl: this,
-      ),
-    );
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
-  TaskConstMeta get kCrateApiSimpleCalculateFibonacciConstMeta =>
-      const TaskConstMeta(debugName: "calculate_fibonacci", argNames: ["n"]);
+  @protected
+  void sse_encode_list_worker_pool_metrics(
+    List<WorkerPoolMetrics> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_worker_pool_metrics(item, serializer);
+    }
+  }
 
-  @override
-  Future<String> crateApiSimpleGreet({required String name}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
+  @protected
+  void sse_encode_locale_info(LocaleInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.locale, serializer);
+    sse_encode_list_String(self.fallbacks, serializer);
+    sse_encode_writing_direction(self.direction, serializer);
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  @protected
+  void sse_encode_log_field(LogField self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.value, serializer);
+  }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  void sse_encode_log_level(LogLevel self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  void sse_encode_log_record(LogRecord self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_log_level(self.level, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_i_64(self.timestampMillis, serializer);
+    sse_encode_list_log_field(self.fields, serializer);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_name_warning(NameWarning self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case NameWarning_Normalized():
+        sse_encode_i_32(0, serializer);
+      case NameWarning_RemovedCharacters(codePoints: final codePoints):
+        sse_encode_i_32(1, serializer);
+        sse_encode_list_prim_u_32_strict(codePoints, serializer);
+      case NameWarning_MixedScript(word: final word, scripts: final scripts):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(word, serializer);
+        sse_encode_list_String(scripts, serializer);
+    }
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_opt_box_autoadd_emoji_set(
+    EmojiSet? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_emoji_set(self, serializer);
+    }
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_opt_box_autoadd_gender(
+    Gender? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_gender(self, serializer);
+    }
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_opt_box_autoadd_greeting_style(
+    GreetingStyle? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_greeting_style(self, serializer);
+    }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_plugin_config(
+    PluginConfig? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_plugin_config(self, serializer);
+    }
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_task_priority(
+    TaskPriority? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_task_priority(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_text_mode(
+    TextMode? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_text_mode(self, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_overflow_policy(
+    OverflowPolicy self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_param_schema(ParamSchema self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.typeName, serializer);
+    sse_encode_bool(self.optional, serializer);
+    sse_encode_list_String(self.values, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_plugin_config(PluginConfig self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_log_level(self.logLevel, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.workerThreads, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.blockingThreads, serializer);
+    sse_encode_opt_String(self.dataDirectory, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_plugin_error(PluginError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    switch (self) {
+      case PluginError_Overflow(operation: final operation):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(operation, serializer);
+      case PluginError_InvalidArgument(name: final name, reason: final reason):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(name, serializer);
+        sse_encode_String(reason, serializer);
+      case PluginError_Cancelled():
+        sse_encode_i_32(2, serializer);
+      case PluginError_Internal(details: final details):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(details, serializer);
+    }
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_processed_name(ProcessedName self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_32(self.graphemes, serializer);
+    sse_encode_list_String(self.scripts, serializer);
+    sse_encode_list_name_warning(self.warnings, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_progress_update(
+    ProgressUpdate self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_f_64(self.fraction, serializer);
+    sse_encode_opt_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_reduction(Reduction self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_task_priority(TaskPriority self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_text_mode(TextMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_time_of_day(TimeOfDay self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
@@ -324,14 +4522,121 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_worker_pool_kind(
+    WorkerPoolKind self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_i_32(self.index, serializer);
   }
+
+  @protected
+  void sse_encode_worker_pool_metrics(
+    WorkerPoolMetrics self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_worker_pool_kind(self.pool, serializer);
+    sse_encode_u_32(self.threads, serializer);
+    sse_encode_u_32(self.busy, serializer);
+    sse_encode_u_32(self.queued, serializer);
+    sse_encode_u_64(self.completed, serializer);
+  }
+
+  @protected
+  void sse_encode_writing_direction(
+    WritingDirection self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+}
+
+@sealed
+class CancellationTokenImpl extends RustOpaque implements CancellationToken {
+  // Not to be used by end users
+  CancellationTokenImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  CancellationTokenImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_CancellationToken,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_CancellationToken,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_CancellationTokenPtr,
+  );
+
+  void cancel() =>
+      RustLib.instance.api.crateApiCancellationCancellationTokenCancel(
+        that: this,
+      );
+
+  bool isCancelled() =>
+      RustLib.instance.api.crateApiCancellationCancellationTokenIsCancelled(
+        that: this,
+      );
+}
+
+@sealed
+class NativeBufferImpl extends RustOpaque implements NativeBuffer {
+  // Not to be used by end users
+  NativeBufferImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  NativeBufferImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_NativeBuffer,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_NativeBuffer,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_NativeBufferPtr,
+  );
+
+  BigInt address() =>
+      RustLib.instance.api.crateApiBufferNativeBufferAddress(that: this);
+
+  bool isEmpty() =>
+      RustLib.instance.api.crateApiBufferNativeBufferIsEmpty(that: this);
+
+  BigInt len() =>
+      RustLib.instance.api.crateApiBufferNativeBufferLen(that: this);
+}
+
+@sealed
+class ProgressReporterImpl extends RustOpaque implements ProgressReporter {
+  // Not to be used by end users
+  ProgressReporterImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  ProgressReporterImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_ProgressReporter,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ProgressReporter,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ProgressReporterPtr,
+  );
+
+  Stream<ProgressUpdate> updates() =>
+      RustLib.instance.api.crateApiProgressProgressReporterUpdates(that: this);
 }
@@ -6,7 +6,21 @@
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/batch.dart';
+import 'api/buffer.dart';
+import 'api/cancellation.dart';
+import 'api/dispatch.dart';
+import 'api/error.dart';
+import 'api/info.dart';
+import 'api/init.dart';
+import 'api/locale.dart';
+import 'api/logging.dart';
+import 'api/progress.dart';
 import 'api/simple.dart';
+import 'api/style.dart';
+import 'api/templates.dart';
+import 'api/time.dart';
+import 'api/workers.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -20,66 +34,935 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_CancellationTokenPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_NativeBufferPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_ProgressReporterPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+  @protected
+  CancellationToken dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    dynamic raw,
+  );
+
+  @protected
+  NativeBuffer dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    dynamic raw,
+  );
+
+  @protected
+  ProgressReporter dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    dynamic raw,
+  );
+
+  @protected
+  NativeBuffer dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    dynamic raw,
+  );
+
+  @protected
+  CancellationToken dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    dynamic raw,
+  );
+
+  @protected
+  NativeBuffer dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    dynamic raw,
+  );
+
+  @protected
+  ProgressReporter dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    dynamic raw,
+  );
+
+  @protected
+  CancellationToken dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    dynamic raw,
+  );
+
+  @protected
+  NativeBuffer dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    dynamic raw,
+  );
+
+  @protected
+  ProgressReporter dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<FibonacciChunk> dco_decode_StreamSink_fibonacci_chunk_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<LogRecord> dco_decode_StreamSink_log_record_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<ProgressUpdate> dco_decode_StreamSink_progress_update_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  String dco_decode_String(dynamic raw);
+
+  @protected
+  BatchOp dco_decode_batch_op(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  EmojiSet dco_decode_box_autoadd_emoji_set(dynamic raw);
+
+  @protected
+  Gender dco_decode_box_autoadd_gender(dynamic raw);
+
+  @protected
+  GreetingStyle dco_decode_box_autoadd_greeting_style(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  PluginConfig dco_decode_box_autoadd_plugin_config(dynamic raw);
+
+  @protected
+  PluginError dco_decode_box_autoadd_plugin_error(dynamic raw);
+
+  @protected
+  TaskPriority dco_decode_box_autoadd_task_priority(dynamic raw);
+
+  @protected
+  TextMode dco_decode_box_autoadd_text_mode(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  BuildInfo dco_decode_build_info(dynamic raw);
+
+  @protected
+  EmojiSet dco_decode_emoji_set(dynamic raw);
+
+  @protected
+  EmojiSupport dco_decode_emoji_support(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  FibonacciChunk dco_decode_fibonacci_chunk(dynamic raw);
+
+  @protected
+  FunctionSchema dco_decode_function_schema(dynamic raw);
+
+  @protected
+  Gender dco_decode_gender(dynamic raw);
+
+  @protected
+  GreetingStyle dco_decode_greeting_style(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<FunctionSchema> dco_decode_list_function_schema(dynamic raw);
+
+  @protected
+  List<LogField> dco_decode_list_log_field(dynamic raw);
+
+  @protected
+  List<NameWarning> dco_decode_list_name_warning(dynamic raw);
+
+  @protected
+  List<ParamSchema> dco_decode_list_param_schema(dynamic raw);
+
+  @protected
+  List<double> dco_decode_list_prim_f_64_loose(dynamic raw);
+
+  @protected
+  Float64List dco_decode_list_prim_f_64_strict(dynamic raw);
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_loose(dynamic raw);
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<WorkerPoolMetrics> dco_decode_list_worker_pool_metrics(dynamic raw);
+
+  @protected
+  LocaleInfo dco_decode_locale_info(dynamic raw);
+
+  @protected
+  LogField dco_decode_log_field(dynamic raw);
+
+  @protected
+  LogLevel dco_decode_log_level(dynamic raw);
+
+  @protected
+  LogRecord dco_decode_log_record(dynamic raw);
+
+  @protected
+  NameWarning dco_decode_name_warning(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  EmojiSet? dco_decode_opt_box_autoadd_emoji_set(dynamic raw);
+
+  @protected
+  Gender? dco_decode_opt_box_autoadd_gender(dynamic raw);
+
+  @protected
+  GreetingStyle? dco_decode_opt_box_autoadd_greeting_style(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  PluginConfig? dco_decode_opt_box_autoadd_plugin_config(dynamic raw);
+
+  @protected
+  TaskPriority? dco_decode_opt_box_autoadd_task_priority(dynamic raw);
+
+  @protected
+  TextMode? dco_decode_opt_box_autoadd_text_mode(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  OverflowPolicy dco_decode_overflow_policy(dynamic raw);
+
+  @protected
+  ParamSchema dco_decode_param_schema(dynamic raw);
+
+  @protected
+  PluginConfig dco_decode_plugin_config(dynamic raw);
+
+  @protected
+  PluginError dco_decode_plugin_error(dynamic raw);
+
+  @protected
+  ProcessedName dco_decode_processed_name(dynamic raw);
+
+  @protected
+  ProgressUpdate dco_decode_progress_update(dynamic raw);
+
+  @protected
+  Reduction dco_decode_reduction(dynamic raw);
+
+  @protected
+  TaskPriority dco_decode_task_priority(dynamic raw);
+
+  @protected
+  TextMode dco_decode_text_mode(dynamic raw);
+
+  @protected
+  TimeOfDay dco_decode_time_of_day(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  WorkerPoolKind dco_decode_worker_pool_kind(dynamic raw);
+
+  @protected
+  WorkerPoolMetrics dco_decode_worker_pool_metrics(dynamic raw);
+
+  @protected
+  WritingDirection dco_decode_writing_direction(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  CancellationToken sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NativeBuffer sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProgressReporter sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NativeBuffer sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CancellationToken sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NativeBuffer sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProgressReporter sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CancellationToken sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NativeBuffer sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProgressReporter sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<FibonacciChunk> sse_decode_StreamSink_fibonacci_chunk_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<LogRecord> sse_decode_StreamSink_log_record_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<ProgressUpdate> sse_decode_StreamSink_progress_update_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  BatchOp sse_decode_batch_op(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  EmojiSet sse_decode_box_autoadd_emoji_set(SseDeserializer deserializer);
+
+  @protected
+  Gender sse_decode_box_autoadd_gender(SseDeserializer deserializer);
+
+  @protected
+  GreetingStyle sse_decode_box_autoadd_greeting_style(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  PluginConfig sse_decode_box_autoadd_plugin_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PluginError sse_decode_box_autoadd_plugin_error(SseDeserializer deserializer);
+
+  @protected
+  TaskPriority sse_decode_box_autoadd_task_priority(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TextMode sse_decode_box_autoadd_text_mode(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  BuildInfo sse_decode_build_info(SseDeserializer deserializer);
+
+  @protected
+  EmojiSet sse_decode_emoji_set(SseDeserializer deserializer);
+
+  @protected
+  EmojiSupport sse_decode_emoji_support(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FibonacciChunk sse_decode_fibonacci_chunk(SseDeserializer deserializer);
+
+  @protected
+  FunctionSchema sse_decode_function_schema(SseDeserializer deserializer);
+
+  @protected
+  Gender sse_decode_gender(SseDeserializer deserializer);
+
+  @protected
+  GreetingStyle sse_decode_greeting_style(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<FunctionSchema> sse_decode_list_function_schema(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LogField> sse_decode_list_log_field(SseDeserializer deserializer);
+
+  @protected
+  List<NameWarning> sse_decode_list_name_warning(SseDeserializer deserializer);
+
+  @protected
+  List<ParamSchema> sse_decode_list_param_schema(SseDeserializer deserializer);
+
+  @protected
+  List<double> sse_decode_list_prim_f_64_loose(SseDeserializer deserializer);
+
+  @protected
+  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer);
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_loose(SseDeserializer deserializer);
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<WorkerPoolMetrics> sse_decode_list_worker_pool_metrics(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LocaleInfo sse_decode_locale_info(SseDeserializer deserializer);
+
+  @protected
+  LogField sse_decode_log_field(SseDeserializer deserializer);
+
+  @protected
+  LogLevel sse_decode_log_level(SseDeserializer deserializer);
+
+  @protected
+  LogRecord sse_decode_log_record(SseDeserializer deserializer);
+
+  @protected
+  NameWarning sse_decode_name_warning(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  EmojiSet? sse_decode_opt_box_autoadd_emoji_set(SseDeserializer deserializer);
+
+  @protected
+  Gender? sse_decode_opt_box_autoadd_gender(SseDeserializer deserializer);
+
+  @protected
+  GreetingStyle? sse_decode_opt_box_autoadd_greeting_style(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  PluginConfig? sse_decode_opt_box_autoadd_plugin_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TaskPriority? sse_decode_opt_box_autoadd_task_priority(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TextMode? sse_decode_opt_box_autoadd_text_mode(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  OverflowPolicy sse_decode_overflow_policy(SseDeserializer deserializer);
+
+  @protected
+  ParamSchema sse_decode_param_schema(SseDeserializer deserializer);
+
+  @protected
+  PluginConfig sse_decode_plugin_config(SseDeserializer deserializer);
+
+  @protected
+  PluginError sse_decode_plugin_error(SseDeserializer deserializer);
+
+  @protected
+  ProcessedName sse_decode_processed_name(SseDeserializer deserializer);
+
+  @protected
+  ProgressUpdate sse_decode_progress_update(SseDeserializer deserializer);
+
+  @protected
+  Reduction sse_decode_reduction(SseDeserializer deserializer);
+
+  @protected
+  TaskPriority sse_decode_task_priority(SseDeserializer deserializer);
+
+  @protected
+  TextMode sse_decode_text_mode(SseDeserializer deserializer);
+
+  @protected
+  TimeOfDay sse_decode_time_of_day(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
   @protected
-  String dco_decode_String(dynamic raw);
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+  int sse_decode_u_8(SseDeserializer deserializer);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_decode_unit(SseDeserializer deserializer);
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  BigInt sse_decode_usize(SseDeserializer deserializer);
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  WorkerPoolKind sse_decode_worker_pool_kind(SseDeserializer deserializer);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  WorkerPoolMetrics sse_decode_worker_pool_metrics(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  WritingDirection sse_decode_writing_direction(SseDeserializer deserializer);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    CancellationToken self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    NativeBuffer self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    ProgressReporter self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    NativeBuffer self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    CancellationToken self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    NativeBuffer self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    ProgressReporter self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(
+    CancellationToken self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(
+    NativeBuffer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(
+    ProgressReporter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_fibonacci_chunk_Sse(
+    RustStreamSink<FibonacciChunk> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_log_record_Sse(
+    RustStreamSink<LogRecord> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_progress_update_Sse(
+    RustStreamSink<ProgressUpdate> self,
+    SseSerializer serializer,
+  );
 
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_batch_op(BatchOp self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_emoji_set(
+    EmojiSet self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_gender(Gender self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_greeting_style(
+    GreetingStyle self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_plugin_config(
+    PluginConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_plugin_error(
+    PluginError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_task_priority(
+    TaskPriority self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_text_mode(
+    TextMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_build_info(BuildInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_emoji_set(EmojiSet self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_emoji_support(EmojiSupport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_fibonacci_chunk(
+    FibonacciChunk self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_function_schema(
+    FunctionSchema self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_gender(Gender self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_greeting_style(GreetingStyle self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_function_schema(
+    List<FunctionSchema> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_log_field(List<LogField> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_name_warning(
+    List<NameWarning> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_param_schema(
+    List<ParamSchema> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_f_64_loose(
+    List<double> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_f_64_strict(
+    Float64List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_i_64_loose(
+    Int64List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_i_64_strict(
+    Int64List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_worker_pool_metrics(
+    List<WorkerPoolMetrics> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_locale_info(LocaleInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_log_field(LogField self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_log_level(LogLevel self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_log_record(LogRecord self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_name_warning(NameWarning self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_emoji_set(
+    EmojiSet? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_gender(
+    Gender? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_greeting_style(
+    GreetingStyle? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_plugin_config(
+    PluginConfig? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_task_priority(
+    TaskPriority? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_text_mode(
+    TextMode? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_overflow_policy(
+    OverflowPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_param_schema(ParamSchema self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_plugin_config(PluginConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_plugin_error(PluginError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_processed_name(ProcessedName self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_progress_update(
+    ProgressUpdate self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_reduction(Reduction self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_task_priority(TaskPriority self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_text_mode(TextMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_time_of_day(TimeOfDay self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -93,16 +976,49 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_unit(void self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_usize(BigInt self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_worker_pool_kind(
+    WorkerPoolKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_worker_pool_metrics(
+    WorkerPoolMetrics self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_writing_direction(
+    WritingDirection self,
+    SseSerializer serializer,
+  );
 }
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
   RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(int ptr) =>
+      wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(int ptr) =>
+      wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(ptr);
+
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(int ptr) =>
+      wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(int ptr) =>
+      wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(ptr);
+
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(int ptr) =>
+      wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(int ptr) =>
+      wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(ptr);
 }
 
 @JS('wasm_bindgen')
@@ -110,4 +1026,16 @@ external RustLibWasmModule get wasmModule;
 
 @JS()
 @anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {}
+extension type RustLibWasmModule._(JSObject _) implements JSObject {
+  external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(int ptr);
+
+  external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(int ptr);
+
+  external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(int ptr);
+
+  external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNativeBuffer(int ptr);
+
+  external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(int ptr);
+
+  external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProgressReporter(int ptr);
+}
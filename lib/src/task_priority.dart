@@ -0,0 +1,18 @@
+import 'rust/api/workers.dart';
+
+/// Runs [call], queueing the async native call it starts at [priority].
+///
+/// [call] must start the native call before its first `await`, e.g.
+/// `withTaskPriority(TaskPriority.interactive, () => calculateFibonacciBig(...))`.
+/// Only that call gets [priority]; later calls follow their routes again.
+Future<T> withTaskPriority<T>(
+  TaskPriority priority,
+  Future<T> Function() call,
+) {
+  setNextCallPriority(priority: priority);
+  try {
+    return call();
+  } finally {
+    setNextCallPriority();
+  }
+}
@@ -0,0 +1,575 @@
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use serde_json::{json, Map, Value};
+use syn::{
+    Attribute, Expr, FnArg, GenericArgument, ImplItem, Item, Lit, Pat, PathArguments, ReturnType,
+    Signature, Type,
+};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    // Outside a checkout, e.g. in the pub cache, a missing path would rerun this on every build.
+    if let Some(git_dir) = command_output("git", &["rev-parse", "--git-dir"]) {
+        for path in ["HEAD", "refs"].map(|name| Path::new(&git_dir).join(name)) {
+            if path.exists() {
+                println!("cargo:rerun-if-changed={}", path.display());
+            }
+        }
+    }
+    println!("cargo:rerun-if-env-changed=GITHUB_SHA");
+    println!("cargo:rerun-if-changed=src/frb_generated.rs");
+    println!("cargo:rerun-if-changed=src/api");
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=src/handshake.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let git_commit = command_output("git", &["rev-parse", "HEAD"])
+        .or_else(|| env::var("GITHUB_SHA").ok())
+        .unwrap_or_else(|| "unknown".into());
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+    let rustc_version = command_output(&rustc, &["--version"]).unwrap_or_else(|| "unknown".into());
+    let mut features: Vec<String> = env::vars()
+        .filter_map(|(key, _)| {
+            key.strip_prefix("CARGO_FEATURE_")
+                .map(|name| name.to_lowercase().replace('_', "-"))
+        })
+        .collect();
+    features.sort();
+
+    println!("cargo:rustc-env=BUILD_GIT_COMMIT={git_commit}");
+    println!("cargo:rustc-env=BUILD_RUSTC_VERSION={rustc_version}");
+    println!(
+        "cargo:rustc-env=BUILD_TARGET={}",
+        env::var("TARGET").unwrap()
+    );
+    println!(
+        "cargo:rustc-env=BUILD_PROFILE={}",
+        env::var("PROFILE").unwrap()
+    );
+    println!("cargo:rustc-env=BUILD_FEATURES={}", features.join(","));
+
+    let surface = write_api_surface();
+    write_api_schema(&surface);
+    write_header();
+}
+
+/// Write the C header for `src/ffi.rs` and `src/handshake.rs` to
+/// `$OUT_DIR/demo_rust_native_plugin.h`
+fn write_header() {
+    cbindgen::Builder::new()
+        .with_config(cbindgen::Config::from_file("cbindgen.toml").unwrap())
+        .with_src("src/ffi.rs")
+        .with_src("src/handshake.rs")
+        .generate()
+        .expect("failed to generate the C header")
+        .write_to_file(Path::new(&env::var("OUT_DIR").unwrap()).join("demo_rust_native_plugin.h"));
+}
+
+/// Write `API_FUNCTIONS`, the func IDs the generated dispatchers accept, to `$OUT_DIR/api_surface.rs`
+///
+/// Returns the wire name of each function and whether it is sync.
+fn write_api_surface() -> Vec<(String, bool)> {
+    let mut surface = Vec::new();
+    let generated = fs::read_to_string("src/frb_generated.rs").unwrap();
+    let mut table = String::from("&[\n");
+    for (dispatcher, sync) in [
+        ("fn pde_ffi_dispatcher_primary_impl(", false),
+        ("fn pde_ffi_dispatcher_sync_impl(", true),
+    ] {
+        let body = &generated[generated.find(dispatcher).unwrap()..];
+        let body = &body[..body.find("_ => unreachable!()").unwrap()];
+        let mut arms = body.split(" => wire__");
+        let mut before = arms.next().unwrap();
+        for arm in arms {
+            let func_id: i32 = before
+                .rsplit(|c: char| !c.is_ascii_digit())
+                .next()
+                .and_then(|digits| digits.parse().ok())
+                .unwrap();
+            let wire_name = &arm[..arm.find("_impl(").unwrap()];
+            let (path, signature) = describe(wire_name);
+            surface.push((wire_name.to_owned(), sync));
+            writeln!(table, "    ({func_id}, {path:?}, {signature:?}, {sync}),").unwrap();
+            before = arm;
+        }
+    }
+    table.push(']');
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("api_surface.rs");
+    fs::write(out, table).unwrap();
+    surface
+}
+
+/// Rust path and signature of the function behind a wire name such as `crate__api__simple__greet`
+fn describe(wire_name: &str) -> (String, String) {
+    let (module, owner, name) = split_wire_name(wire_name);
+    let file = module.rsplit("__").next().unwrap();
+    let source = fs::read_to_string(format!("src/api/{file}.rs")).unwrap();
+    // Search only the owner's impl block for methods.
+    let source = match owner {
+        Some(owner) => &source[source.find(&format!("impl {owner} {{")).unwrap()..],
+        None => source.as_str(),
+    };
+    let text = &source[source.find(&format!("fn {name}(")).unwrap()..];
+    let mut signature = text[..text.find(" {").unwrap()]
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace("( ", "(")
+        .replace(", )", ")")
+        .replace("(mut ", "(")
+        .replace(", mut ", ", ");
+    let mut path = module.replace("__", "::");
+    if let Some(owner) = owner {
+        signature = signature
+            .replacen("fn ", &format!("fn {owner}::"), 1)
+            .replace("Self", owner);
+        path = format!("{path}::{owner}");
+    }
+    (format!("{path}::{name}"), signature)
+}
+
+/// Module, owner type and name of a wire name; methods are named `Owner_method`
+fn split_wire_name(wire_name: &str) -> (&str, Option<&str>, &str) {
+    let (module, item) = wire_name.rsplit_once("__").unwrap();
+    match item.split_once('_') {
+        Some((owner, method)) if owner.starts_with(char::is_uppercase) => {
+            (module, Some(owner), method)
+        }
+        _ => (module, None, item),
+    }
+}
+
+/// Write a JSON Schema of the functions in `surface` and the types they use to
+/// `$OUT_DIR/api_schema.json`, for `api_schema()`
+fn write_api_schema(surface: &[(String, bool)]) {
+    let api = ApiSource::load();
+    let mut refs = Vec::new();
+    let mut functions: Vec<Value> = surface
+        .iter()
+        .map(|(wire_name, sync)| api.function(wire_name, *sync, &mut refs))
+        .collect();
+    functions.sort_by(|a, b| a["path"].as_str().cmp(&b["path"].as_str()));
+
+    let mut defs = BTreeMap::new();
+    while let Some(name) = refs.pop() {
+        if let Entry::Vacant(slot) = defs.entry(name) {
+            let def = api.type_def(slot.key(), &mut refs);
+            slot.insert(def);
+        }
+    }
+
+    let generated = fs::read_to_string("src/frb_generated.rs").unwrap();
+    let content_hash: i64 = generated
+        .split("FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = ")
+        .nth(1)
+        .and_then(|rest| rest[..rest.find(';').unwrap()].parse().ok())
+        .unwrap();
+    let schema = json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "demo_rust_native_plugin API",
+        "description": "Functions and types of `crate::api`, with values as they appear in JSON",
+        "version": env::var("CARGO_PKG_VERSION").unwrap(),
+        "content_hash": content_hash,
+        "functions": functions,
+        "$defs": defs,
+    });
+    let text = serde_json::to_string_pretty(&schema).unwrap() + "\n";
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("api_schema.json");
+    fs::write(out, text).unwrap();
+}
+
+/// The parsed `src/api` modules
+struct ApiSource {
+    files: Vec<(String, syn::File)>,
+}
+
+impl ApiSource {
+    fn load() -> Self {
+        let mut files: Vec<_> = fs::read_dir("src/api")
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+            .map(|path| {
+                let module = path.file_stem().unwrap().to_string_lossy().into_owned();
+                let source = fs::read_to_string(&path).unwrap();
+                (module, syn::parse_file(&source).unwrap())
+            })
+            .collect();
+        files.sort_by(|a, b| a.0.cmp(&b.0));
+        ApiSource { files }
+    }
+
+    fn items(&self) -> impl Iterator<Item = &Item> {
+        self.files.iter().flat_map(|(_, file)| &file.items)
+    }
+
+    /// Methods and associated functions of `owner`
+    fn impl_fns<'a>(&'a self, owner: &'a str) -> impl Iterator<Item = &'a syn::ImplItemFn> {
+        self.items()
+            .filter_map(move |item| match item {
+                Item::Impl(block)
+                    if block.trait_.is_none() && type_name(&block.self_ty) == owner =>
+                {
+                    Some(&block.items)
+                }
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|item| match item {
+                ImplItem::Fn(f) => Some(f),
+                _ => None,
+            })
+    }
+
+    fn function(&self, wire_name: &str, sync: bool, refs: &mut Vec<String>) -> Value {
+        let (module, owner, name) = split_wire_name(wire_name);
+        let file = module.rsplit("__").next().unwrap();
+        let (attrs, sig): (&[Attribute], &Signature) = match owner {
+            Some(owner) => self
+                .impl_fns(owner)
+                .find(|f| f.sig.ident == name)
+                .map(|f| (&f.attrs[..], &f.sig)),
+            None => self
+                .files
+                .iter()
+                .find(|(module, _)| module == file)
+                .and_then(|(_, file)| {
+                    file.items.iter().find_map(|item| match item {
+                        Item::Fn(f) if f.sig.ident == name => Some((&f.attrs[..], &f.sig)),
+                        _ => None,
+                    })
+                }),
+        }
+        .unwrap_or_else(|| panic!("no source for {wire_name}"));
+
+        let mut params = Vec::new();
+        let mut stream = None;
+        let mut takes_self = false;
+        for input in &sig.inputs {
+            let FnArg::Typed(input) = input else {
+                takes_self = true;
+                continue;
+            };
+            let Pat::Ident(pat) = &*input.pat else {
+                panic!("{wire_name} has a pattern parameter");
+            };
+            if let Some(item) = generic_arg(&input.ty, "StreamSink") {
+                stream = Some(type_schema(item, owner, refs));
+                continue;
+            }
+            params.push(json!({
+                "name": pat.ident.to_string(),
+                "required": generic_arg(&input.ty, "Option").is_none(),
+                "schema": type_schema(&input.ty, owner, refs),
+            }));
+        }
+        let (returns, errors) = match &sig.output {
+            ReturnType::Default => (json!({ "type": "null" }), None),
+            ReturnType::Type(_, ty) => match generic_args(ty, "Result").as_deref() {
+                Some([ok, error]) => (
+                    type_schema(ok, owner, refs),
+                    Some(type_schema(error, owner, refs)),
+                ),
+                _ => (type_schema(ty, owner, refs), None),
+            },
+        };
+
+        let mut function = Map::new();
+        function.insert("name".into(), name.into());
+        function.insert(
+            "path".into(),
+            match owner {
+                Some(owner) => format!("{}::{owner}::{name}", module.replace("__", "::")),
+                None => format!("{}::{name}", module.replace("__", "::")),
+            }
+            .into(),
+        );
+        if let Some(doc) = doc(attrs) {
+            function.insert("description".into(), doc.into());
+        }
+        function.insert("sync".into(), sync.into());
+        if let Some(owner) = owner {
+            function.insert("owner".into(), owner.into());
+            function.insert("takes_self".into(), takes_self.into());
+        }
+        function.insert("params".into(), params.into());
+        function.insert("returns".into(), returns);
+        if let Some(stream) = stream {
+            function.insert("stream".into(), stream);
+        }
+        if let Some(errors) = errors {
+            function.insert("errors".into(), errors);
+        }
+        function.into()
+    }
+
+    /// Schema of the struct or enum `name`, as serde writes it
+    fn type_def(&self, name: &str, refs: &mut Vec<String>) -> Value {
+        let item = self
+            .items()
+            .find(|item| match item {
+                Item::Struct(s) => s.ident == name,
+                Item::Enum(e) => e.ident == name,
+                _ => false,
+            })
+            .unwrap_or_else(|| panic!("no definition of {name} in src/api"));
+        let attrs = match item {
+            Item::Struct(s) => &s.attrs,
+            Item::Enum(e) => &e.attrs,
+            _ => unreachable!(),
+        };
+        let mut def = Map::new();
+        if let Some(doc) = doc(attrs) {
+            def.insert("description".into(), doc.into());
+        }
+        if has_attr(attrs, "frb", "opaque") {
+            // Handles are passed by reference and never serialized.
+            def.insert("x-opaque".into(), true.into());
+            return def.into();
+        }
+        match item {
+            Item::Struct(s) => {
+                let (properties, required) = fields_schema(&s.fields, refs);
+                def.insert("type".into(), "object".into());
+                def.insert("properties".into(), properties.into());
+                def.insert("required".into(), required.into());
+            }
+            Item::Enum(e) => {
+                let snake_case = has_attr(attrs, "serde", "rename_all = \"snake_case\"");
+                let tag = serde_tag(attrs);
+                let codes = self.variant_codes(name);
+                let variants: Vec<Value> = e
+                    .variants
+                    .iter()
+                    .map(|variant| {
+                        let ident = variant.ident.to_string();
+                        let value = if snake_case {
+                            to_snake_case(&ident)
+                        } else {
+                            ident.clone()
+                        };
+                        let mut schema = Map::new();
+                        match &tag {
+                            Some(tag) => {
+                                let (fields, field_names) = fields_schema(&variant.fields, refs);
+                                let mut properties = Map::new();
+                                properties.insert(tag.clone(), json!({ "const": value }));
+                                properties.extend(fields);
+                                let mut required = vec![tag.clone()];
+                                required.extend(field_names);
+                                schema.insert("type".into(), "object".into());
+                                schema.insert("properties".into(), properties.into());
+                                schema.insert("required".into(), required.into());
+                            }
+                            None if variant.fields.is_empty() => {
+                                schema.insert("const".into(), value.into());
+                            }
+                            None => {
+                                panic!("{name}::{ident} has fields but {name} has no serde tag")
+                            }
+                        }
+                        if let Some(doc) = doc(&variant.attrs) {
+                            schema.insert("description".into(), doc.into());
+                        }
+                        if let Some(code) = codes.get(&ident) {
+                            schema.insert("x-code".into(), (*code).into());
+                        }
+                        schema.into()
+                    })
+                    .collect();
+                if tag.is_none() {
+                    def.insert("type".into(), "string".into());
+                }
+                def.insert("oneOf".into(), variants.into());
+            }
+            _ => unreachable!(),
+        }
+        def.into()
+    }
+
+    /// Codes returned by `name::code()` per variant, e.g. `PluginError::Overflow { .. } => 1`
+    fn variant_codes(&self, name: &str) -> HashMap<String, i64> {
+        let mut codes = HashMap::new();
+        let Some(code) = self.impl_fns(name).find(|f| f.sig.ident == "code") else {
+            return codes;
+        };
+        let Some(syn::Stmt::Expr(Expr::Match(body), _)) = code.block.stmts.last() else {
+            return codes;
+        };
+        for arm in &body.arms {
+            let path = match &arm.pat {
+                Pat::Struct(p) => &p.path,
+                Pat::TupleStruct(p) => &p.path,
+                Pat::Path(p) => &p.path,
+                _ => continue,
+            };
+            if let Expr::Lit(syn::ExprLit {
+                lit: Lit::Int(code),
+                ..
+            }) = &*arm.body
+            {
+                let variant = path.segments.last().unwrap().ident.to_string();
+                codes.insert(variant, code.base10_parse().unwrap());
+            }
+        }
+        codes
+    }
+}
+
+/// Properties and required property names of named fields
+fn fields_schema(
+    fields: &syn::Fields,
+    refs: &mut Vec<String>,
+) -> (Map<String, Value>, Vec<String>) {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for field in fields {
+        let name = field.ident.as_ref().unwrap().to_string();
+        let mut schema = type_schema(&field.ty, None, refs);
+        if let Some(doc) = doc(&field.attrs) {
+            schema["description"] = doc.into();
+        }
+        if generic_arg(&field.ty, "Option").is_none() {
+            required.push(name.clone());
+        }
+        properties.insert(name, schema);
+    }
+    (properties, required)
+}
+
+/// JSON Schema of a Rust type; named types become `$ref`s and are added to `refs`
+fn type_schema(ty: &Type, owner: Option<&str>, refs: &mut Vec<String>) -> Value {
+    let ty = match ty {
+        Type::Reference(reference) => &*reference.elem,
+        Type::Tuple(tuple) if tuple.elems.is_empty() => return json!({ "type": "null" }),
+        ty => ty,
+    };
+    if let Some(item) = generic_arg(ty, "Vec") {
+        return json!({ "type": "array", "items": type_schema(item, owner, refs) });
+    }
+    if let Some(inner) = generic_arg(ty, "Option") {
+        return json!({ "anyOf": [type_schema(inner, owner, refs), { "type": "null" }] });
+    }
+    let name = type_name(ty);
+    match name.as_str() {
+        "String" | "str" => json!({ "type": "string" }),
+        "bool" => json!({ "type": "boolean" }),
+        "f32" | "f64" => json!({ "type": "number", "format": name }),
+        "i8" | "i16" | "i32" | "i64" | "isize" => json!({ "type": "integer", "format": name }),
+        "u8" | "u16" | "u32" | "u64" | "usize" => {
+            json!({ "type": "integer", "format": name, "minimum": 0 })
+        }
+        _ => {
+            let name = match name.as_str() {
+                "Self" => owner.unwrap().to_owned(),
+                _ => name,
+            };
+            let reference = format!("#/$defs/{name}");
+            refs.push(name);
+            json!({ "$ref": reference })
+        }
+    }
+}
+
+/// Last path segment of a type, e.g. `Vec` for `Vec<u8>`
+fn type_name(ty: &Type) -> String {
+    match ty {
+        Type::Path(path) => path.path.segments.last().unwrap().ident.to_string(),
+        _ => panic!("unsupported type in src/api: {ty:?}"),
+    }
+}
+
+/// Type arguments of `ty` if it is the generic type `name`
+fn generic_args<'a>(ty: &'a Type, name: &str) -> Option<Vec<&'a Type>> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    (segment.ident == name).then(|| {
+        args.args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect()
+    })
+}
+
+fn generic_arg<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    generic_args(ty, name).and_then(|args| args.first().copied())
+}
+
+/// Text of the `///` comments
+fn doc(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta.require_name_value().ok()?.value {
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Str(text),
+                ..
+            }) => Some(text.value()),
+            _ => None,
+        })
+        .map(|line| line.strip_prefix(' ').unwrap_or(&line).to_owned())
+        .collect();
+    let doc = lines.join("\n").trim().to_owned();
+    (!doc.is_empty()).then_some(doc)
+}
+
+/// Whether an attribute whose path ends in `name` mentions `needle`, e.g. `frb(opaque)`
+fn has_attr(attrs: &[Attribute], name: &str, needle: &str) -> bool {
+    attrs.iter().any(|attr| {
+        let Ok(list) = attr.meta.require_list() else {
+            return false;
+        };
+        list.path.segments.last().is_some_and(|s| s.ident == name)
+            && list.tokens.to_string().contains(needle)
+    })
+}
+
+/// The `tag` of an internally tagged serde enum
+fn serde_tag(attrs: &[Attribute]) -> Option<String> {
+    let mut tag = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tag") {
+                tag = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+            } else if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<Expr>()?;
+            }
+            Ok(())
+        });
+    }
+    tag
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8(output.stdout).ok()?;
+    Some(text.trim().to_owned())
+}
@@ -0,0 +1,87 @@
+/* Stable C API of demo_rust_native_plugin; see src/ffi.rs for ownership rules. */
+
+#ifndef DEMO_RUST_NATIVE_PLUGIN_H
+#define DEMO_RUST_NATIVE_PLUGIN_H
+
+/* Generated by build.rs with cbindgen; do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Bumped whenever a function or type in this API changes incompatibly
+#define DEMO_RUST_NATIVE_PLUGIN_ABI_VERSION 1
+
+// Result of a C API call; failure codes equal `PluginError::code()`
+typedef enum DemoRustNativePluginStatus {
+  DEMO_RUST_NATIVE_PLUGIN_STATUS_OK = 0,
+  DEMO_RUST_NATIVE_PLUGIN_STATUS_OVERFLOW = 1,
+  DEMO_RUST_NATIVE_PLUGIN_STATUS_INVALID_ARGUMENT = 2,
+  DEMO_RUST_NATIVE_PLUGIN_STATUS_CANCELLED = 3,
+  DEMO_RUST_NATIVE_PLUGIN_STATUS_INTERNAL = 4,
+} DemoRustNativePluginStatus;
+
+// How `demo_rust_native_plugin_add_numbers` handles results that do not fit in `int64_t`
+typedef enum DemoRustNativePluginOverflowPolicy {
+  DEMO_RUST_NATIVE_PLUGIN_OVERFLOW_POLICY_CHECKED = 0,
+  DEMO_RUST_NATIVE_PLUGIN_OVERFLOW_POLICY_SATURATING = 1,
+  DEMO_RUST_NATIVE_PLUGIN_OVERFLOW_POLICY_WRAPPING = 2,
+} DemoRustNativePluginOverflowPolicy;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Version of this API; compare with `DEMO_RUST_NATIVE_PLUGIN_ABI_VERSION`
+uint32_t demo_rust_native_plugin_abi_version(void);
+
+// Write the greeting for `name` to `*out`
+//
+// # Safety
+//
+// `name` must be a NUL-terminated string and `out` must be valid for writes.
+enum DemoRustNativePluginStatus demo_rust_native_plugin_greet(const char *name, char **out);
+
+// Write `F(n)` to `*out`; fails with an overflow status for `n > 93`
+//
+// # Safety
+//
+// `out` must be valid for writes.
+enum DemoRustNativePluginStatus demo_rust_native_plugin_calculate_fibonacci(uint32_t n,
+                                                                            uint64_t *out);
+
+// Write `a + b` to `*out`, handling overflow according to `policy`
+//
+// `policy` is a `DemoRustNativePluginOverflowPolicy` value; others fail with an
+// invalid argument status.
+//
+// # Safety
+//
+// `out` must be valid for writes.
+enum DemoRustNativePluginStatus demo_rust_native_plugin_add_numbers(int64_t a,
+                                                                    int64_t b,
+                                                                    uint32_t policy,
+                                                                    int64_t *out);
+
+// Description of the last error on this thread, or NULL if the last call succeeded
+//
+// Release the result with `demo_rust_native_plugin_free_string`.
+char *demo_rust_native_plugin_last_error_message(void);
+
+// Release a string returned by this library
+//
+// # Safety
+//
+// `s` must be NULL or a pointer returned by this library that has not been released yet.
+void demo_rust_native_plugin_free_string(char *s);
+
+// JSON description of the API and build; release it with `demo_rust_native_plugin_free_string`
+char *demo_rust_native_plugin_api_surface(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* DEMO_RUST_NATIVE_PLUGIN_H */
@@ -0,0 +1,11 @@
+greeting = { $gender ->
+    [feminine] مرحباً يا { $name }، أهلاً بكِ!
+    [masculine] مرحباً يا { $name }، أهلاً بكَ!
+   *[other] مرحباً يا { $name }!
+}
+salutation = مرحباً
+time-of-day-greeting = { $time_of_day ->
+    [morning] صباح الخير يا { $name }!
+    [afternoon] مساء الخير يا { $name }!
+   *[evening] مساء الخير يا { $name }!
+}
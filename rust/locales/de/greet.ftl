@@ -0,0 +1,7 @@
+greeting = Hallo, { $name }!
+salutation = Hallo
+time-of-day-greeting = { $time_of_day ->
+    [morning] Guten Morgen, { $name }!
+    [afternoon] Guten Tag, { $name }!
+   *[evening] Guten Abend, { $name }!
+}
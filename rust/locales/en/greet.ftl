@@ -0,0 +1,13 @@
+# $name: who to greet
+# $gender: "feminine", "masculine" or "other", for languages whose wording depends on it
+greeting = Hello, { $name }!
+
+# The word of greeting alone, for `salutation` in greeting templates
+salutation = Hello
+
+# $time_of_day: "morning", "afternoon", "evening" or "night" where the person is
+time-of-day-greeting = { $time_of_day ->
+    [morning] Good morning, { $name }!
+    [afternoon] Good afternoon, { $name }!
+   *[evening] Good evening, { $name }!
+}
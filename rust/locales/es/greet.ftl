@@ -0,0 +1,11 @@
+greeting = { $gender ->
+    [feminine] ¡Hola, { $name }, bienvenida!
+    [masculine] ¡Hola, { $name }, bienvenido!
+   *[other] ¡Hola, { $name }!
+}
+salutation = Hola
+time-of-day-greeting = { $time_of_day ->
+    [morning] ¡Buenos días, { $name }!
+    [afternoon] ¡Buenas tardes, { $name }!
+   *[evening] ¡Buenas noches, { $name }!
+}
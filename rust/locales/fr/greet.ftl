@@ -0,0 +1,11 @@
+greeting = { $gender ->
+    [feminine] Bonjour { $name }, bienvenue !
+    [masculine] Bonjour { $name }, bienvenu !
+   *[other] Bonjour { $name } !
+}
+salutation = Bonjour
+time-of-day-greeting = { $time_of_day ->
+    [morning] Bonjour { $name } !
+    [afternoon] Bonjour { $name } !
+   *[evening] Bonsoir { $name } !
+}
@@ -0,0 +1,11 @@
+greeting = { $gender ->
+    [feminine] שלום { $name }, ברוכה הבאה!
+    [masculine] שלום { $name }, ברוך הבא!
+   *[other] שלום { $name }!
+}
+salutation = שלום
+time-of-day-greeting = { $time_of_day ->
+    [morning] בוקר טוב, { $name }!
+    [afternoon] צהריים טובים, { $name }!
+   *[evening] ערב טוב, { $name }!
+}
@@ -0,0 +1,7 @@
+greeting = नमस्ते, { $name }!
+salutation = नमस्ते
+time-of-day-greeting = { $time_of_day ->
+    [morning] सुप्रभात, { $name }!
+    [afternoon] नमस्ते, { $name }!
+   *[evening] शुभ संध्या, { $name }!
+}
@@ -0,0 +1,11 @@
+greeting = { $gender ->
+    [feminine] Ciao { $name }, benvenuta!
+    [masculine] Ciao { $name }, benvenuto!
+   *[other] Ciao { $name }!
+}
+salutation = Ciao
+time-of-day-greeting = { $time_of_day ->
+    [morning] Buongiorno, { $name }!
+    [afternoon] Buon pomeriggio, { $name }!
+   *[evening] Buonasera, { $name }!
+}
@@ -0,0 +1,7 @@
+greeting = こんにちは、{ $name }さん！
+salutation = こんにちは
+time-of-day-greeting = { $time_of_day ->
+    [morning] おはようございます、{ $name }さん！
+    [afternoon] こんにちは、{ $name }さん！
+   *[evening] こんばんは、{ $name }さん！
+}
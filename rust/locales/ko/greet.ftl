@@ -0,0 +1,7 @@
+greeting = 안녕하세요, { $name }님!
+salutation = 안녕하세요
+time-of-day-greeting = { $time_of_day ->
+    [morning] 좋은 아침입니다, { $name }님!
+    [afternoon] 안녕하세요, { $name }님!
+   *[evening] 좋은 저녁입니다, { $name }님!
+}
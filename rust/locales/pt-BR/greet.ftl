@@ -0,0 +1,11 @@
+greeting = { $gender ->
+    [feminine] Oi, { $name }, bem-vinda!
+    [masculine] Oi, { $name }, bem-vindo!
+   *[other] Oi, { $name }!
+}
+salutation = Oi
+time-of-day-greeting = { $time_of_day ->
+    [morning] Bom dia, { $name }!
+    [afternoon] Boa tarde, { $name }!
+   *[evening] Boa noite, { $name }!
+}
@@ -0,0 +1,11 @@
+greeting = { $gender ->
+    [feminine] Olá, { $name }, bem-vinda!
+    [masculine] Olá, { $name }, bem-vindo!
+   *[other] Olá, { $name }!
+}
+salutation = Olá
+time-of-day-greeting = { $time_of_day ->
+    [morning] Bom dia, { $name }!
+    [afternoon] Boa tarde, { $name }!
+   *[evening] Boa noite, { $name }!
+}
@@ -0,0 +1,7 @@
+greeting = Привет, { $name }!
+salutation = Привет
+time-of-day-greeting = { $time_of_day ->
+    [morning] Доброе утро, { $name }!
+    [afternoon] Добрый день, { $name }!
+   *[evening] Добрый вечер, { $name }!
+}
@@ -0,0 +1,7 @@
+greeting = Merhaba { $name }!
+salutation = Merhaba
+time-of-day-greeting = { $time_of_day ->
+    [morning] Günaydın { $name }!
+    [afternoon] İyi günler { $name }!
+   *[evening] İyi akşamlar { $name }!
+}
@@ -0,0 +1,7 @@
+greeting = 你好，{ $name }！
+salutation = 你好
+time-of-day-greeting = { $time_of_day ->
+    [morning] 早上好，{ $name }！
+    [afternoon] 下午好，{ $name }！
+   *[evening] 晚上好，{ $name }！
+}
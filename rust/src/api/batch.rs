@@ -0,0 +1,264 @@
+use num_bigint::{BigInt, Sign};
+use rayon::iter::{
+    FromParallelIterator, IndexedParallelIterator, IntoParallelIterator, ParallelIterator,
+};
+use rayon::slice::ParallelSliceMut;
+use serde::{Deserialize, Serialize};
+
+use crate::api::error::PluginError;
+use crate::api::simple::OverflowPolicy;
+
+/// Inputs shorter than this are processed on the calling worker thread
+const PARALLEL_THRESHOLD: usize = 16 * 1024;
+
+/// Block length for parallel prefix sums; fixed so results do not depend on the thread count
+const SCAN_CHUNK: usize = 4 * 1024;
+
+/// Element-wise operation applied by `batch_i64` and `batch_f64`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchOp {
+    Add,
+    Subtract,
+    Multiply,
+    /// Integer division rounds toward zero
+    Divide,
+}
+
+/// Reduction applied by `reduce_i64` and `reduce_f64`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Reduction {
+    /// Zero for an empty list
+    Sum,
+    /// One for an empty list
+    Product,
+    /// Fails for an empty list
+    Min,
+    /// Fails for an empty list
+    Max,
+}
+
+/// Apply `op` to each pair `a[i]`, `b[i]`
+///
+/// Integer results are computed exactly, then `policy` decides what happens to
+/// those that do not fit in `i64`. Division by zero fails under every policy.
+pub fn batch_i64(
+    a: Vec<i64>,
+    b: Vec<i64>,
+    op: BatchOp,
+    policy: OverflowPolicy,
+) -> Result<Vec<i64>, PluginError> {
+    check_same_len(a.len(), b.len())?;
+    map_indexed(a.len(), |i| {
+        let (x, y) = (i128::from(a[i]), i128::from(b[i]));
+        let exact = match op {
+            BatchOp::Add => x + y,
+            BatchOp::Subtract => x - y,
+            BatchOp::Multiply => x * y,
+            BatchOp::Divide if y == 0 => {
+                return Err(PluginError::invalid_argument(
+                    "b",
+                    format!("division by zero at index {i}"),
+                ));
+            }
+            BatchOp::Divide => x / y,
+        };
+        narrow(exact, policy, || format!("batch_i64({op:?}) at index {i}"))
+    })
+}
+
+/// Apply `op` to each pair `a[i]`, `b[i]` with IEEE 754 semantics
+pub fn batch_f64(a: Vec<f64>, b: Vec<f64>, op: BatchOp) -> Result<Vec<f64>, PluginError> {
+    check_same_len(a.len(), b.len())?;
+    Ok(map_indexed(a.len(), |i| match op {
+        BatchOp::Add => a[i] + b[i],
+        BatchOp::Subtract => a[i] - b[i],
+        BatchOp::Multiply => a[i] * b[i],
+        BatchOp::Divide => a[i] / b[i],
+    }))
+}
+
+/// Sum of `a[i] * b[i]`
+pub fn dot_product_i64(
+    a: Vec<i64>,
+    b: Vec<i64>,
+    policy: OverflowPolicy,
+) -> Result<i64, PluginError> {
+    check_same_len(a.len(), b.len())?;
+    let product = |i: usize| i128::from(a[i]) * i128::from(b[i]);
+    // Wrapping in i128 agrees with wrapping in i64, so only the other policies need the
+    // exact sum.
+    let add: fn(Option<i128>, Option<i128>) -> Option<i128> = match policy {
+        OverflowPolicy::Wrapping => |x, y| Some(x?.wrapping_add(y?)),
+        _ => |x, y| x?.checked_add(y?),
+    };
+    let sum = if a.len() < PARALLEL_THRESHOLD {
+        (0..a.len()).map(|i| Some(product(i))).fold(Some(0), add)
+    } else {
+        (0..a.len())
+            .into_par_iter()
+            .map(|i| Some(product(i)))
+            .reduce(|| Some(0), add)
+    };
+    // A partial sum left i128; the exact sum may still fit, so add again without limits.
+    let sum = sum.unwrap_or_else(|| {
+        let exact: BigInt = (0..a.len()).map(|i| BigInt::from(product(i))).sum();
+        i128::try_from(&exact).unwrap_or(match exact.sign() {
+            Sign::Minus => i128::MIN,
+            _ => i128::MAX,
+        })
+    });
+    narrow(sum, policy, || "dot_product_i64".to_owned())
+}
+
+/// Sum of `a[i] * b[i]`; parallel summation may round differently in the last bits
+pub fn dot_product_f64(a: Vec<f64>, b: Vec<f64>) -> Result<f64, PluginError> {
+    check_same_len(a.len(), b.len())?;
+    Ok(if a.len() < PARALLEL_THRESHOLD {
+        a.iter().zip(&b).map(|(x, y)| x * y).sum()
+    } else {
+        (0..a.len()).into_par_iter().map(|i| a[i] * b[i]).sum()
+    })
+}
+
+/// Running totals: element `i` is the sum of `values[0..=i]`
+pub fn prefix_sums_i64(values: Vec<i64>, policy: OverflowPolicy) -> Result<Vec<i64>, PluginError> {
+    // Sums of i64 cannot overflow i128, so every total is exact before `policy` applies.
+    let totals = scan(
+        values.into_iter().map(i128::from).collect(),
+        i128::wrapping_add,
+    );
+    map_indexed(totals.len(), |i| {
+        narrow(totals[i], policy, || {
+            format!("prefix_sums_i64 at index {i}")
+        })
+    })
+}
+
+/// Running totals: element `i` is the sum of `values[0..=i]`
+pub fn prefix_sums_f64(values: Vec<f64>) -> Vec<f64> {
+    scan(values, |x, y| x + y)
+}
+
+/// Fold `values` into one number
+///
+/// Sums and products are computed exactly before `policy` applies.
+pub fn reduce_i64(
+    values: Vec<i64>,
+    reduction: Reduction,
+    policy: OverflowPolicy,
+) -> Result<i64, PluginError> {
+    let wide = |i: usize| i128::from(values[i]);
+    let (identity, combine): (i128, fn(i128, i128) -> i128) = match reduction {
+        Reduction::Sum => (0, i128::wrapping_add),
+        // Magnitudes never shrink when multiplying non-zero integers, so a
+        // saturated product keeps the right sign.
+        Reduction::Product => match policy {
+            OverflowPolicy::Wrapping => (1, i128::wrapping_mul),
+            _ => (1, i128::saturating_mul),
+        },
+        Reduction::Min | Reduction::Max if values.is_empty() => {
+            return Err(PluginError::invalid_argument("values", "must not be empty"));
+        }
+        Reduction::Min => (i128::MAX, i128::min),
+        Reduction::Max => (i128::MIN, i128::max),
+    };
+    let result = if values.len() < PARALLEL_THRESHOLD {
+        (0..values.len()).map(wide).fold(identity, combine)
+    } else {
+        (0..values.len())
+            .into_par_iter()
+            .map(wide)
+            .reduce(|| identity, combine)
+    };
+    narrow(result, policy, || format!("reduce_i64({reduction:?})"))
+}
+
+/// Fold `values` into one number; parallel sums may round differently in the last bits
+///
+/// `Min` and `Max` ignore NaN unless every element is NaN.
+pub fn reduce_f64(values: Vec<f64>, reduction: Reduction) -> Result<f64, PluginError> {
+    let (identity, combine): (f64, fn(f64, f64) -> f64) = match reduction {
+        Reduction::Sum => (0.0, |x, y| x + y),
+        Reduction::Product => (1.0, |x, y| x * y),
+        Reduction::Min | Reduction::Max if values.is_empty() => {
+            return Err(PluginError::invalid_argument("values", "must not be empty"));
+        }
+        Reduction::Min => (f64::NAN, f64::min),
+        Reduction::Max => (f64::NAN, f64::max),
+    };
+    Ok(if values.len() < PARALLEL_THRESHOLD {
+        values.into_iter().fold(identity, combine)
+    } else {
+        values.into_par_iter().reduce(|| identity, combine)
+    })
+}
+
+fn check_same_len(a: usize, b: usize) -> Result<(), PluginError> {
+    if a == b {
+        Ok(())
+    } else {
+        Err(PluginError::invalid_argument(
+            "b",
+            format!("has length {b} but `a` has length {a}"),
+        ))
+    }
+}
+
+/// Convert an exact result to `i64` according to `policy`
+fn narrow(
+    value: i128,
+    policy: OverflowPolicy,
+    operation: impl FnOnce() -> String,
+) -> Result<i64, PluginError> {
+    match policy {
+        OverflowPolicy::Checked => {
+            i64::try_from(value).map_err(|_| PluginError::overflow(operation()))
+        }
+        OverflowPolicy::Saturating => Ok(value.clamp(i64::MIN.into(), i64::MAX.into()) as i64),
+        OverflowPolicy::Wrapping => Ok(value as i64),
+    }
+}
+
+/// Collect `f(0..len)`, in parallel once `len` reaches the threshold
+fn map_indexed<R, C>(len: usize, f: impl Fn(usize) -> R + Sync + Send) -> C
+where
+    R: Send,
+    C: FromIterator<R> + FromParallelIterator<R>,
+{
+    if len < PARALLEL_THRESHOLD {
+        (0..len).map(f).collect()
+    } else {
+        (0..len).into_par_iter().map(f).collect()
+    }
+}
+
+/// Inclusive prefix scan; large inputs scan fixed-size blocks in parallel and then add block offsets
+fn scan<T>(mut values: Vec<T>, add: fn(T, T) -> T) -> Vec<T>
+where
+    T: Copy + Send + Sync,
+{
+    let scan_block = |block: &mut [T]| {
+        for i in 1..block.len() {
+            block[i] = add(block[i - 1], block[i]);
+        }
+    };
+    if values.len() < PARALLEL_THRESHOLD {
+        scan_block(&mut values);
+        return values;
+    }
+    values.par_chunks_mut(SCAN_CHUNK).for_each(scan_block);
+    let mut offsets: Vec<T> = values.chunks(SCAN_CHUNK).map(|c| c[c.len() - 1]).collect();
+    scan_block(&mut offsets);
+    values
+        .par_chunks_mut(SCAN_CHUNK)
+        .skip(1)
+        .zip(offsets.into_par_iter())
+        .for_each(|(block, offset)| {
+            for v in block {
+                *v = add(offset, *v);
+            }
+        });
+    values
+}
@@ -0,0 +1,79 @@
+use crate::api::error::PluginError;
+
+/// Native byte buffer that Dart can view in place through `dart:ffi`
+///
+/// Passing a `NativeBuffer` to Rust hands over an opaque handle, so large payloads
+/// skip the per-byte encoding that `Vec<u8>` arguments go through. Dart must not
+/// write to its view while a Rust call is using the buffer.
+#[flutter_rust_bridge::frb(opaque)]
+pub struct NativeBuffer {
+    data: Box<[u8]>,
+}
+
+impl NativeBuffer {
+    /// Allocate `len` zeroed bytes
+    ///
+    /// Fails with [`PluginError::InvalidArgument`] if the memory cannot be allocated.
+    pub fn new(len: usize) -> Result<Self, PluginError> {
+        let mut data = Vec::new();
+        data.try_reserve_exact(len)
+            .map_err(|e| PluginError::invalid_argument("len", e.to_string()))?;
+        data.resize(len, 0);
+        Ok(Self {
+            data: data.into_boxed_slice(),
+        })
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Address of the first byte; valid until the buffer is disposed
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn address(&self) -> usize {
+        self.data.as_ptr() as usize
+    }
+}
+
+/// Adler-32 checksum of `data`
+pub fn checksum_bytes(data: Vec<u8>) -> u32 {
+    adler32(&data)
+}
+
+/// Adler-32 checksum of the buffer contents
+pub fn checksum_buffer(buffer: &NativeBuffer) -> u32 {
+    adler32(&buffer.data)
+}
+
+/// XOR every byte with `key`, returning a new list
+pub fn xor_bytes(mut data: Vec<u8>, key: u8) -> Vec<u8> {
+    data.iter_mut().for_each(|b| *b ^= key);
+    data
+}
+
+/// XOR every byte of the buffer with `key` in place
+pub fn xor_buffer(buffer: &mut NativeBuffer, key: u8) {
+    buffer.data.iter_mut().for_each(|b| *b ^= key);
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    // Largest block whose sums cannot overflow u32 before reduction
+    const BLOCK: usize = 5552;
+    let (mut a, mut b) = (1u32, 0u32);
+    for block in data.chunks(BLOCK) {
+        for &byte in block {
+            a += u32::from(byte);
+            b += a;
+        }
+        a %= MOD;
+        b %= MOD;
+    }
+    (b << 16) | a
+}
@@ -0,0 +1,38 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::api::error::PluginError;
+
+/// Cooperative cancellation flag created in Dart and passed to long-running calls
+///
+/// Calls observing a cancelled token stop early and fail with `PluginError::Cancelled`.
+#[flutter_rust_bridge::frb(opaque)]
+#[derive(Debug, Default)]
+pub struct CancellationToken {
+    cancelled: AtomicBool,
+}
+
+impl CancellationToken {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation of every call holding this token
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn check(&self) -> Result<(), PluginError> {
+        if self.is_cancelled() {
+            Err(PluginError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
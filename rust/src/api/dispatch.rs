@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::cancellation::CancellationToken;
+use crate::api::error::PluginError;
+use crate::api::progress::ProgressReporter;
+use crate::registry::{self, Context, Ty};
+
+/// Most JSON a streaming function may produce through [`invoke`], which holds it all in memory
+const MAX_STREAMED_BYTES: usize = 16 << 20;
+
+/// A function that [`invoke`] can call
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionSchema {
+    /// The Rust name, e.g. `calculate_fibonacci`
+    pub name: String,
+    pub params: Vec<ParamSchema>,
+    /// Rust type of the result, e.g. `Vec<i64>`
+    pub returns: String,
+    /// Type of the items of a streaming function, which `invoke` returns as a list
+    pub stream: Option<String>,
+}
+
+/// A parameter of a [`FunctionSchema`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParamSchema {
+    pub name: String,
+    /// Rust type, e.g. `u32` or `Option<u64>`
+    pub type_name: String,
+    /// Whether the argument may be left out
+    pub optional: bool,
+    /// Accepted values of an enum parameter, empty otherwise
+    pub values: Vec<String>,
+}
+
+/// Call the `crate::api` function `name` with a JSON object of arguments keyed by parameter name
+///
+/// Returns the result as JSON; for streaming functions it is the list of streamed items,
+/// which fails with [`PluginError::InvalidArgument`] once it passes 16 MiB. Call those
+/// functions directly to consume larger streams. Functions that need handles are not
+/// available; see [`list_functions`].
+pub fn invoke(name: String, args_json: String) -> Result<String, PluginError> {
+    log::debug!(name = name.as_str(); "invoke");
+    let function = registry::find(&name)
+        .ok_or_else(|| PluginError::invalid_argument("name", format!("no function `{name}`")))?;
+    let args: Map<String, Value> = serde_json::from_str(&args_json)
+        .map_err(|e| PluginError::invalid_argument("args_json", e.to_string()))?;
+
+    let mut items = Vec::new();
+    let mut streamed_bytes = 0;
+    let result = function.call(
+        args,
+        &mut Context {
+            cancellation: &CancellationToken::new(),
+            progress: &ProgressReporter::new(0),
+            emit: &mut |item| {
+                streamed_bytes += item.to_string().len();
+                items.push(item);
+                // Returning `false` ends the stream.
+                streamed_bytes <= MAX_STREAMED_BYTES
+            },
+        },
+    )?;
+    if streamed_bytes > MAX_STREAMED_BYTES {
+        return Err(PluginError::invalid_argument(
+            "args_json",
+            format!(
+                "`{name}` streams more than {} MiB; call it directly to consume its stream",
+                MAX_STREAMED_BYTES >> 20
+            ),
+        ));
+    }
+    let result = match function.stream {
+        Some(_) => Value::Array(items),
+        None => result,
+    };
+    Ok(result.to_string())
+}
+
+/// Every function [`invoke`] can call, with its parameters
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_functions() -> Vec<FunctionSchema> {
+    registry::functions()
+        .iter()
+        .map(|f| FunctionSchema {
+            name: f.name.to_owned(),
+            params: f
+                .params
+                .iter()
+                .map(|p| ParamSchema {
+                    name: p.name.to_owned(),
+                    type_name: p.ty.to_string(),
+                    optional: p.ty != optional_inner(p.ty),
+                    values: match optional_inner(p.ty) {
+                        Ty::Enum(_, values) => values.iter().map(|v| (*v).to_owned()).collect(),
+                        _ => Vec::new(),
+                    },
+                })
+                .collect(),
+            returns: f.returns.to_string(),
+            stream: f.stream.map(|ty| ty.to_string()),
+        })
+        .collect()
+}
+
+fn optional_inner(ty: Ty) -> Ty {
+    match ty {
+        Ty::Optional(inner) => *inner,
+        ty => ty,
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Errors reported by the plugin API
+///
+/// Each variant arrives in Dart as its own exception class.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PluginError {
+    /// The result does not fit in the return type
+    Overflow { operation: String },
+    /// An argument is outside the range accepted by the function
+    InvalidArgument { name: String, reason: String },
+    /// The call was cancelled before it finished
+    Cancelled,
+    /// An unexpected failure inside the plugin
+    Internal { details: String },
+}
+
+impl PluginError {
+    /// Stable numeric code of the error, suitable for logs and analytics
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn code(&self) -> i32 {
+        match self {
+            PluginError::Overflow { .. } => 1,
+            PluginError::InvalidArgument { .. } => 2,
+            PluginError::Cancelled => 3,
+            PluginError::Internal { .. } => 4,
+        }
+    }
+
+    /// Human-readable description of the error
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn message(&self) -> String {
+        self.to_string()
+    }
+
+    pub(crate) fn overflow(operation: impl Into<String>) -> Self {
+        PluginError::Overflow {
+            operation: operation.into(),
+        }
+    }
+
+    pub(crate) fn invalid_argument(name: impl Into<String>, reason: impl Into<String>) -> Self {
+        PluginError::InvalidArgument {
+            name: name.into(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for PluginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PluginError::Overflow { operation } => write!(f, "arithmetic overflow in {operation}"),
+            PluginError::InvalidArgument { name, reason } => {
+                write!(f, "invalid argument `{name}`: {reason}")
+            }
+            PluginError::Cancelled => write!(f, "operation was cancelled"),
+            PluginError::Internal { details } => write!(f, "internal error: {details}"),
+        }
+    }
+}
+
+impl std::error::Error for PluginError {}
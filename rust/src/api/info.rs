@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::frb_generated::{
+    FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH, FLUTTER_RUST_BRIDGE_CODEGEN_VERSION,
+};
+
+/// Identifies the native library that is actually loaded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildInfo {
+    pub crate_version: String,
+    /// `git rev-parse HEAD` at build time, or `unknown`
+    pub git_commit: String,
+    pub rustc_version: String,
+    /// Target triple, e.g. `aarch64-linux-android`
+    pub target: String,
+    /// Enabled cargo features, sorted
+    pub features: Vec<String>,
+    /// `debug` or `release`
+    pub profile: String,
+    pub frb_codegen_version: String,
+    /// Content hash of the generated bindings the library was built with
+    pub frb_content_hash: i32,
+    /// Release of the embedded IANA time zone database, e.g. `2025b`
+    pub tzdb_version: String,
+}
+
+/// Build and version information of the loaded native library
+#[flutter_rust_bridge::frb(sync)]
+pub fn build_info() -> BuildInfo {
+    BuildInfo {
+        crate_version: env!("CARGO_PKG_VERSION").to_owned(),
+        git_commit: env!("BUILD_GIT_COMMIT").to_owned(),
+        rustc_version: env!("BUILD_RUSTC_VERSION").to_owned(),
+        target: env!("BUILD_TARGET").to_owned(),
+        features: env!("BUILD_FEATURES")
+            .split(',')
+            .filter(|f| !f.is_empty())
+            .map(str::to_owned)
+            .collect(),
+        profile: env!("BUILD_PROFILE").to_owned(),
+        frb_codegen_version: FLUTTER_RUST_BRIDGE_CODEGEN_VERSION.to_owned(),
+        frb_content_hash: FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH,
+        tzdb_version: chrono_tz::IANA_TZDB_VERSION.to_owned(),
+    }
+}
+
+/// JSON Schema of the functions and types in `crate::api`
+///
+/// The same document is committed as `rust/schema/api.json`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn api_schema() -> String {
+    include_str!(concat!(env!("OUT_DIR"), "/api_schema.json")).to_owned()
+}
@@ -0,0 +1,153 @@
+use std::backtrace::Backtrace;
+use std::path::Path;
+use std::sync::{Mutex, Once};
+
+use log::LevelFilter;
+use serde::{Deserialize, Serialize};
+
+use crate::api::error::PluginError;
+use crate::api::info::build_info;
+use crate::api::workers::WorkerPoolKind;
+use crate::{executor, logger};
+
+static INIT: Once = Once::new();
+static CONFIG: Mutex<Option<PluginConfig>> = Mutex::new(None);
+/// Held while `init_plugin` applies a configuration, so that `CONFIG` is only locked
+/// to read or replace it and `plugin_config` never waits on I/O
+static APPLYING: Mutex<()> = Mutex::new(());
+
+/// Verbosity of the Rust log output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<LogLevel> for LevelFilter {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Off => LevelFilter::Off,
+            LogLevel::Error => LevelFilter::Error,
+            LogLevel::Warn => LevelFilter::Warn,
+            LogLevel::Info => LevelFilter::Info,
+            LogLevel::Debug => LevelFilter::Debug,
+            LogLevel::Trace => LevelFilter::Trace,
+        }
+    }
+}
+
+impl From<log::Level> for LogLevel {
+    fn from(level: log::Level) -> Self {
+        match level {
+            log::Level::Error => LogLevel::Error,
+            log::Level::Warn => LogLevel::Warn,
+            log::Level::Info => LogLevel::Info,
+            log::Level::Debug => LogLevel::Debug,
+            log::Level::Trace => LogLevel::Trace,
+        }
+    }
+}
+
+/// Plugin configuration passed to `init_plugin`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginConfig {
+    /// Most verbose level that is logged
+    pub log_level: LogLevel,
+    /// Threads in the CPU pool, at most 256; `None` uses one per CPU
+    pub worker_threads: Option<u32>,
+    /// Threads in the blocking I/O pool, at most 256; `None` uses 4
+    pub blocking_threads: Option<u32>,
+    /// Directory for files the plugin writes; created if missing
+    pub data_directory: Option<String>,
+}
+
+/// Install the panic hook and logger; run automatically by `RustLib.init()`
+#[flutter_rust_bridge::frb(init)]
+pub fn init_app() {
+    INIT.call_once(|| {
+        logger::install();
+        install_panic_hook();
+        let info = build_info();
+        log::info!(
+            "demo_rust_native_plugin {} ({}, {}, {})",
+            info.crate_version,
+            info.git_commit,
+            info.target,
+            info.profile,
+        );
+    });
+}
+
+/// Apply `config` to the plugin
+///
+/// Safe to call more than once: each call re-applies the log level and pool
+/// sizes, while `data_directory` is fixed by the first call. Fails with
+/// [`PluginError::Internal`] if the system cannot start the threads, keeping
+/// those it started.
+pub fn init_plugin(config: PluginConfig) -> Result<(), PluginError> {
+    init_app();
+    for (name, threads) in [
+        ("worker_threads", config.worker_threads),
+        ("blocking_threads", config.blocking_threads),
+    ] {
+        match threads {
+            Some(0) => return Err(PluginError::invalid_argument(name, "must be at least 1")),
+            Some(n) if n > executor::MAX_POOL_THREADS => {
+                return Err(PluginError::invalid_argument(
+                    name,
+                    format!("must be at most {}", executor::MAX_POOL_THREADS),
+                ))
+            }
+            _ => {}
+        }
+    }
+
+    let _applying = APPLYING.lock().unwrap();
+    let initialized = match CONFIG.lock().unwrap().as_ref() {
+        Some(current) if current.data_directory != config.data_directory => {
+            return Err(PluginError::invalid_argument(
+                "data_directory",
+                "cannot change after the plugin is initialized",
+            ));
+        }
+        current => current.is_some(),
+    };
+    if let (false, Some(dir)) = (initialized, &config.data_directory) {
+        std::fs::create_dir_all(Path::new(dir))
+            .map_err(|e| PluginError::invalid_argument("data_directory", e.to_string()))?;
+    }
+
+    logger::set_level(config.log_level.into());
+    for (kind, threads) in [
+        (WorkerPoolKind::Cpu, config.worker_threads),
+        (WorkerPoolKind::Blocking, config.blocking_threads),
+    ] {
+        executor::set_pool_threads(kind, threads.map(|n| n as usize)).map_err(|e| {
+            PluginError::Internal {
+                details: format!("cannot start {kind:?} pool threads: {e}"),
+            }
+        })?;
+    }
+    log::debug!("plugin initialized with {config:?}");
+    *CONFIG.lock().unwrap() = Some(config);
+    Ok(())
+}
+
+/// The configuration applied by the last successful `init_plugin` call
+#[flutter_rust_bridge::frb(sync)]
+pub fn plugin_config() -> Option<PluginConfig> {
+    CONFIG.lock().unwrap().clone()
+}
+
+fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        log::error!(target: "panic", "{info}\n{}", Backtrace::force_capture());
+        previous(info);
+    }));
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::error::PluginError;
+use crate::l10n;
+
+/// Grammatical gender of the person addressed, for languages whose wording depends on it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Gender {
+    Feminine,
+    Masculine,
+    /// Unknown or neither; the wording avoids gendered forms
+    Other,
+}
+
+impl Gender {
+    /// The value of `$gender` in Fluent messages and `gender` in greeting templates
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Gender::Feminine => "feminine",
+            Gender::Masculine => "masculine",
+            Gender::Other => "other",
+        }
+    }
+}
+
+/// Direction in which a locale's script is written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WritingDirection {
+    Ltr,
+    Rtl,
+}
+
+/// How a requested locale is resolved against the available translations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocaleInfo {
+    /// Best available locale, e.g. `pt-BR`
+    pub locale: String,
+    /// Locales messages are looked up in, best first, e.g. `pt-BR`, `pt`, `en`
+    pub fallbacks: Vec<String>,
+    /// Direction of text localized for `locale`; lay out its widgets to match
+    pub direction: WritingDirection,
+}
+
+/// Resolve `requested`, a BCP 47 tag such as `pt-BR`, to the available translations
+///
+/// Tags that do not parse or match nothing resolve to `en`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn negotiate_locale(requested: String) -> LocaleInfo {
+    let fallbacks = l10n::fallback_chain(&requested);
+    let best = &fallbacks[0];
+    LocaleInfo {
+        locale: best.to_string(),
+        direction: if l10n::is_rtl(best) {
+            WritingDirection::Rtl
+        } else {
+            WritingDirection::Ltr
+        },
+        fallbacks: fallbacks.iter().map(ToString::to_string).collect(),
+    }
+}
+
+/// Every locale with translations, including those added with `add_translations`
+#[flutter_rust_bridge::frb(sync)]
+pub fn supported_locales() -> Vec<String> {
+    l10n::locales().iter().map(ToString::to_string).collect()
+}
+
+/// Add Fluent (`.ftl`) messages for `locale`, replacing bundled ones with the same id
+///
+/// A locale without bundled translations becomes available. Fails with
+/// [`PluginError::InvalidArgument`] for a malformed tag or FTL syntax errors.
+pub fn add_translations(locale: String, ftl: String) -> Result<(), PluginError> {
+    log::debug!(locale = locale.as_str(), ftl_len = ftl.len(); "add_translations");
+    let locale = locale
+        .parse()
+        .map_err(|e| PluginError::invalid_argument("locale", format!("{e}")))?;
+    l10n::add_resource(locale, ftl).map_err(|e| PluginError::invalid_argument("ftl", e))
+}
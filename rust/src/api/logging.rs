@@ -0,0 +1,46 @@
+use crate::api::init::LogLevel;
+use crate::frb_generated::StreamSink;
+use crate::logger;
+
+/// A log record emitted by the Rust crate
+#[derive(Debug, Clone)]
+pub struct LogRecord {
+    pub level: LogLevel,
+    /// Module path of the code that logged, unless overridden with `target:`
+    pub target: String,
+    pub message: String,
+    /// Milliseconds since the Unix epoch
+    pub timestamp_millis: i64,
+    /// Structured key/value fields attached to the record
+    pub fields: Vec<LogField>,
+}
+
+/// A key/value field of a log record
+#[derive(Debug, Clone)]
+pub struct LogField {
+    pub key: String,
+    pub value: String,
+}
+
+/// Stream log records to Dart until the subscription is cancelled
+pub fn subscribe_logs(sink: StreamSink<LogRecord>) {
+    logger::add_sink(sink);
+}
+
+/// Set the level for targets without a more specific override
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_log_level(level: LogLevel) {
+    logger::set_level(level.into());
+}
+
+/// Set the level for `target` and its submodules, e.g. `demo_rust_native_plugin::api::simple`
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_log_target_level(target: String, level: LogLevel) {
+    logger::set_target_level(target, level.into());
+}
+
+/// Remove every per-target level set with `set_log_target_level`
+#[flutter_rust_bridge::frb(sync)]
+pub fn clear_log_target_levels() {
+    logger::clear_target_levels();
+}
@@ -1 +1,15 @@
+pub mod batch;
+pub mod buffer;
+pub mod cancellation;
+pub mod dispatch;
+pub mod error;
+pub mod info;
+pub mod init;
+pub mod locale;
+pub mod logging;
+pub mod progress;
 pub mod simple;
+pub mod style;
+pub mod templates;
+pub mod time;
+pub mod workers;
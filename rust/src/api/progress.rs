@@ -0,0 +1,79 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use web_time::Instant;
+
+use crate::frb_generated::StreamSink;
+
+/// A progress update from a long-running call
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressUpdate {
+    /// Completed fraction of the work, from 0.0 to 1.0
+    pub fraction: f64,
+    pub message: Option<String>,
+}
+
+/// Side channel for long-running calls to report progress to Dart
+///
+/// Updates closer together than `min_interval_ms` are dropped, except the final one.
+#[flutter_rust_bridge::frb(opaque)]
+pub struct ProgressReporter {
+    min_interval: Duration,
+    state: Mutex<ReporterState>,
+}
+
+/// Receives updates; returns `false` once nobody is listening any more
+type Listener = Box<dyn FnMut(ProgressUpdate) -> bool + Send>;
+
+#[derive(Default)]
+struct ReporterState {
+    listener: Option<Listener>,
+    last_sent: Option<Instant>,
+}
+
+impl ProgressReporter {
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn new(min_interval_ms: u32) -> Self {
+        Self {
+            min_interval: Duration::from_millis(min_interval_ms.into()),
+            state: Mutex::default(),
+        }
+    }
+
+    /// Stream the updates reported through this reporter, replacing any earlier subscription
+    pub fn updates(&self, sink: StreamSink<ProgressUpdate>) {
+        // A failed send means the Dart side cancelled its subscription
+        self.listen(move |update| sink.add(update).is_ok());
+    }
+
+    /// Like `updates`, for Rust callers without a `StreamSink`
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn listen(&self, listener: impl FnMut(ProgressUpdate) -> bool + Send + 'static) {
+        *self.state.lock().unwrap() = ReporterState {
+            listener: Some(Box::new(listener)),
+            last_sent: None,
+        };
+    }
+
+    pub(crate) fn report(&self, fraction: f64, message: Option<String>) {
+        let fraction = fraction.clamp(0.0, 1.0);
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        let due = fraction >= 1.0
+            || state
+                .last_sent
+                .is_none_or(|last| now.duration_since(last) >= self.min_interval);
+        if !due {
+            return;
+        }
+        let Some(listener) = &mut state.listener else {
+            return;
+        };
+        if listener(ProgressUpdate { fraction, message }) {
+            state.last_sent = Some(now);
+        } else {
+            state.listener = None;
+        }
+    }
+}
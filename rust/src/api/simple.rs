@@ -1,27 +1,479 @@
-/// Simple greeting function
-pub fn greet(name: String) -> String {
-    format!("Hello, {}! 🦀", name)
+use fluent_bundle::FluentArgs;
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+
+use crate::api::cancellation::CancellationToken;
+use crate::api::error::PluginError;
+use crate::api::locale::Gender;
+use crate::api::progress::ProgressReporter;
+use crate::api::style::GreetingStyle;
+use crate::api::time::TimeOfDay;
+use crate::fibonacci;
+use crate::frb_generated::StreamSink;
+use crate::l10n;
+use crate::names;
+use crate::templating::{self, GreetingValues};
+use crate::zones;
+
+/// Greet `name` in `locale`, a BCP 47 tag such as `pt-BR`
+///
+/// `name` is cleaned up with `process_name` first, and fails the same way.
+/// Unavailable locales fall back as in `negotiate_locale`, ending with English. `gender`
+/// picks the wording in languages that need it and defaults to `Gender::Other`. With
+/// `time_zone`, an IANA name such as `America/Sao_Paulo`, the greeting depends on the
+/// time of day there at `now_utc_millis`, or at the current time without it; see
+/// `time_of_day`. With `template`, the greeting is rendered from the template
+/// registered under that name with `register_greeting_template`; templates that use
+/// `time_of_day` need `time_zone`. `style` sets the decoration, 🦀 by default. Unknown
+/// time zones and templates fail with [`PluginError::InvalidArgument`].
+///
+/// `greet_sync` covers greetings without templates or time zones.
+pub fn greet(
+    name: String,
+    locale: Option<String>,
+    gender: Option<Gender>,
+    template: Option<String>,
+    style: Option<GreetingStyle>,
+    time_zone: Option<String>,
+    now_utc_millis: Option<i64>,
+) -> Result<String, PluginError> {
+    log::debug!(
+        name_len = name.len(),
+        locale = locale.as_deref(),
+        template = template.as_deref(),
+        time_zone = time_zone.as_deref();
+        "greet"
+    );
+    let ProcessedName { name, warnings, .. } = process_name(name, None)?;
+    if !warnings.is_empty() {
+        log::warn!(warnings = warnings.len(); "greet cleaned up the name");
+    }
+    let now = zones::instant(now_utc_millis)
+        .map_err(|e| PluginError::invalid_argument("now_utc_millis", e))?;
+    let time_of_day = time_zone
+        .as_deref()
+        .map(zones::parse)
+        .transpose()
+        .map_err(|e| PluginError::invalid_argument("time_zone", e))?
+        .map(|zone| TimeOfDay::from_hour(zones::hour(now, zone)));
+    let template = template
+        .map(|name| {
+            templating::get(&name).ok_or_else(|| {
+                PluginError::invalid_argument("template", format!("no greeting template `{name}`"))
+            })
+        })
+        .transpose()?;
+    if let (Some(template), None) = (&template, time_of_day) {
+        if template.uses_time_of_day() {
+            return Err(PluginError::invalid_argument(
+                "time_zone",
+                "the template uses `time_of_day`, which needs a time zone",
+            ));
+        }
+    }
+
+    let chain = l10n::fallback_chain(locale.as_deref().unwrap_or(l10n::DEFAULT_LOCALE));
+    let gender = gender.unwrap_or(Gender::Other);
+    let name = l10n::isolate(&name, l10n::is_rtl(&chain[0]));
+    let mut args = FluentArgs::new();
+    args.set("name", name.as_str());
+    args.set("gender", gender.as_str());
+    if let Some(time_of_day) = time_of_day {
+        args.set("time_of_day", time_of_day.as_str());
+    }
+    let message = match time_of_day {
+        Some(_) => "time-of-day-greeting",
+        None => "greeting",
+    };
+    let greeting =
+        l10n::format(&chain, message, &args).unwrap_or_else(|| format!("Hello, {name}!"));
+    let style = style.unwrap_or_default();
+    let Some(template) = template else {
+        return Ok(style.apply(&greeting));
+    };
+
+    let values = GreetingValues {
+        salutation: l10n::format(&chain, "salutation", &args).unwrap_or_else(|| "Hello".to_owned()),
+        name,
+        greeting,
+        locale: chain[0].to_string(),
+        gender: gender.as_str(),
+        time_of_day: time_of_day.map(TimeOfDay::as_str),
+    };
+    template
+        .render(&values)
+        .map(|text| style.apply(&text))
+        .map_err(|e| PluginError::invalid_argument("template", e))
+}
+
+/// Something `process_name` changed or noticed about a name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum NameWarning {
+    /// The name, without the removed characters, was not in Unicode normalization form C
+    Normalized,
+    /// Control, invisible or bidirectional formatting characters (e.g. U+202E
+    /// RIGHT-TO-LEFT OVERRIDE) were removed
+    RemovedCharacters { code_points: Vec<u32> },
+    /// A word mixes scripts, e.g. Latin letters with Cyrillic look-alikes, which can
+    /// make it pass for a different name
+    MixedScript { word: String, scripts: Vec<String> },
+}
+
+/// A name cleaned up for display to other users
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessedName {
+    pub name: String,
+    /// User-perceived characters (grapheme clusters) in `name`
+    pub graphemes: u32,
+    /// Unicode scripts of the letters in `name`, e.g. `Latin`, in order of first use
+    pub scripts: Vec<String>,
+    pub warnings: Vec<NameWarning>,
+}
+
+/// Remove control, invisible and bidirectional formatting characters, collapse
+/// whitespace, NFC-normalize the rest and check for mixed-script words
+///
+/// Fails with [`PluginError::InvalidArgument`] if nothing is left, or if the result has
+/// more than `max_graphemes` user-perceived characters (64 by default).
+pub fn process_name(
+    name: String,
+    max_graphemes: Option<u32>,
+) -> Result<ProcessedName, PluginError> {
+    let max = max_graphemes.unwrap_or(names::DEFAULT_MAX_GRAPHEMES);
+    let cleaned = names::clean(&name);
+    if cleaned.name.is_empty() {
+        return Err(PluginError::invalid_argument(
+            "name",
+            "has no visible characters",
+        ));
+    }
+    if cleaned.graphemes > max {
+        return Err(PluginError::invalid_argument(
+            "name",
+            format!(
+                "has {} characters; at most {max} are allowed",
+                cleaned.graphemes
+            ),
+        ));
+    }
+    Ok(ProcessedName {
+        name: cleaned.name,
+        graphemes: cleaned.graphemes,
+        scripts: cleaned.scripts,
+        warnings: cleaned.warnings,
+    })
 }
 
 /// Calculate Fibonacci number
-pub fn calculate_fibonacci(n: u32) -> u64 {
+///
+/// Fails with [`PluginError::Overflow`] for `n > 93`; use `calculate_fibonacci_big` there.
+pub fn calculate_fibonacci(n: u32) -> Result<u64, PluginError> {
+    log::debug!(n; "calculate_fibonacci");
     match n {
-        0 => 0,
-        1 => 1,
+        0 => Ok(0),
+        1 => Ok(1),
         _ => {
             let mut a = 0u64;
             let mut b = 1u64;
             for _ in 2..=n {
-                let temp = a + b;
+                let Some(temp) = a.checked_add(b) else {
+                    log::warn!(n; "calculate_fibonacci overflowed u64");
+                    return Err(PluginError::overflow(format!("calculate_fibonacci({n})")));
+                };
                 a = b;
                 b = temp;
             }
-            b
+            Ok(b)
+        }
+    }
+}
+
+/// Calculate Fibonacci number exactly, optionally reduced modulo `modulus`
+///
+/// The value is returned as a decimal string (parse it with `BigInt.parse` in Dart).
+/// A modulus of zero leaves the result unreduced.
+pub fn calculate_fibonacci_big(
+    n: u32,
+    modulus: Option<u64>,
+    cancellation: &CancellationToken,
+    progress: &ProgressReporter,
+) -> Result<String, PluginError> {
+    log::debug!(n, modulus; "calculate_fibonacci_big");
+    if n > fibonacci::MAX_BIG_N {
+        log::warn!(n; "calculate_fibonacci_big rejected n above {}", fibonacci::MAX_BIG_N);
+        return Err(PluginError::invalid_argument(
+            "n",
+            format!("must be at most {}", fibonacci::MAX_BIG_N),
+        ));
+    }
+    let modulus = modulus.filter(|m| *m != 0).map(BigUint::from);
+    // Doubling steps plus one more for converting the result to decimal
+    let steps = f64::from(u32::BITS - n.leading_zeros() + 1);
+    let value = fibonacci::fibonacci(n, modulus.as_ref(), &mut |done, _| {
+        progress.report(f64::from(done) / steps, None);
+        !cancellation.is_cancelled()
+    })
+    .ok_or(PluginError::Cancelled)?;
+    progress.report((steps - 1.0) / steps, Some("formatting result".into()));
+    let value = value.to_string();
+    progress.report(1.0, None);
+    Ok(value)
+}
+
+/// Most terms in one chunk of `fibonacci_sequence`
+const MAX_CHUNK_SIZE: u32 = 1024;
+
+/// Digits after which `fibonacci_sequence` ends a chunk early
+const MAX_CHUNK_DIGITS: usize = 1 << 20;
+
+/// A run of consecutive Fibonacci numbers emitted by `fibonacci_sequence`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FibonacciChunk {
+    /// Index `n` of the first term
+    pub start: u32,
+    /// `F(start)`, `F(start + 1)`, ... as decimal strings
+    pub terms: Vec<String>,
+}
+
+/// Stream `count` Fibonacci numbers starting at `F(start)`, `chunk_size` terms per event
+///
+/// A `chunk_size` of 1 emits the sequence term by term; at most 1024 are allowed. A chunk
+/// ends early once its digits pass 1 MiB, so long terms are sent as they are ready.
+/// Generation stops once the Dart subscription is cancelled, which is noticed at the next
+/// event; `cancellation` stops it between any two terms.
+pub fn fibonacci_sequence(
+    sink: StreamSink<FibonacciChunk>,
+    start: u32,
+    count: u32,
+    chunk_size: u32,
+    cancellation: &CancellationToken,
+) -> Result<(), PluginError> {
+    // A failed send means the Dart side cancelled its subscription
+    fibonacci_chunks(start, count, chunk_size, cancellation, &mut |chunk| {
+        sink.add(chunk).is_ok()
+    })
+}
+
+/// `fibonacci_sequence` for callers without a `StreamSink`; stops when `emit` returns `false`
+pub(crate) fn fibonacci_chunks(
+    start: u32,
+    count: u32,
+    chunk_size: u32,
+    cancellation: &CancellationToken,
+    emit: &mut dyn FnMut(FibonacciChunk) -> bool,
+) -> Result<(), PluginError> {
+    log::debug!(start, count, chunk_size; "fibonacci_sequence");
+    if chunk_size == 0 || chunk_size > MAX_CHUNK_SIZE {
+        return Err(PluginError::invalid_argument(
+            "chunk_size",
+            format!("must be between 1 and {MAX_CHUNK_SIZE}"),
+        ));
+    }
+    let end = start
+        .checked_add(count)
+        .filter(|end| *end <= fibonacci::MAX_BIG_N + 1)
+        .ok_or_else(|| {
+            PluginError::invalid_argument(
+                "count",
+                format!("last term must be at most F({})", fibonacci::MAX_BIG_N),
+            )
+        })?;
+
+    let (mut a, mut b) =
+        fibonacci::fibonacci_pair(start, None, &mut |_, _| !cancellation.is_cancelled())
+            .ok_or(PluginError::Cancelled)?;
+    let mut index = start;
+    while index < end {
+        let len = chunk_size.min(end - index);
+        let mut terms = Vec::with_capacity(len as usize);
+        let mut digits = 0;
+        // Terms near `MAX_BIG_N` have millions of digits, so check before each one.
+        while terms.len() < len as usize && digits < MAX_CHUNK_DIGITS {
+            cancellation.check()?;
+            let term = a.to_string();
+            digits += term.len();
+            terms.push(term);
+            let next = &a + &b;
+            a = std::mem::replace(&mut b, next);
+        }
+        let len = terms.len() as u32;
+        if !emit(FibonacciChunk {
+            start: index,
+            terms,
+        }) {
+            log::debug!(index; "fibonacci_sequence cancelled");
+            return Ok(());
+        }
+        index += len;
+    }
+    Ok(())
+}
+
+/// How integer arithmetic behaves when the exact result does not fit in `i64`
+///
+/// The chosen policy is applied explicitly, so results are identical in debug and release builds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverflowPolicy {
+    /// Fail with `PluginError::Overflow`
+    Checked,
+    /// Clamp to `i64::MIN` or `i64::MAX`
+    Saturating,
+    /// Wrap around in two's complement
+    Wrapping,
+}
+
+impl OverflowPolicy {
+    fn apply(
+        self,
+        operation: impl FnOnce() -> String,
+        checked: Option<i64>,
+        saturating: impl FnOnce() -> i64,
+        wrapping: impl FnOnce() -> i64,
+    ) -> Result<i64, PluginError> {
+        match self {
+            OverflowPolicy::Checked => checked.ok_or_else(|| {
+                let operation = operation();
+                log::warn!(operation = operation.as_str(); "arithmetic overflow");
+                PluginError::overflow(operation)
+            }),
+            OverflowPolicy::Saturating => Ok(saturating()),
+            OverflowPolicy::Wrapping => Ok(wrapping()),
         }
     }
 }
 
 /// Add two numbers (example with multiple parameters)
-pub fn add_numbers(a: i64, b: i64) -> i64 {
-    a + b
+pub fn add_numbers(a: i64, b: i64, policy: OverflowPolicy) -> Result<i64, PluginError> {
+    policy.apply(
+        || format!("add_numbers({a}, {b})"),
+        a.checked_add(b),
+        || a.saturating_add(b),
+        || a.wrapping_add(b),
+    )
+}
+
+/// Subtract `b` from `a`
+pub fn subtract_numbers(a: i64, b: i64, policy: OverflowPolicy) -> Result<i64, PluginError> {
+    policy.apply(
+        || format!("subtract_numbers({a}, {b})"),
+        a.checked_sub(b),
+        || a.saturating_sub(b),
+        || a.wrapping_sub(b),
+    )
+}
+
+/// Multiply two numbers
+pub fn multiply_numbers(a: i64, b: i64, policy: OverflowPolicy) -> Result<i64, PluginError> {
+    policy.apply(
+        || format!("multiply_numbers({a}, {b})"),
+        a.checked_mul(b),
+        || a.saturating_mul(b),
+        || a.wrapping_mul(b),
+    )
+}
+
+/// Divide `a` by `b`, rounding toward zero
+///
+/// Division by zero is an invalid argument under every policy; only `i64::MIN / -1` overflows.
+pub fn divide_numbers(a: i64, b: i64, policy: OverflowPolicy) -> Result<i64, PluginError> {
+    if b == 0 {
+        log::warn!(a; "divide_numbers by zero");
+        return Err(PluginError::invalid_argument("b", "division by zero"));
+    }
+    policy.apply(
+        || format!("divide_numbers({a}, {b})"),
+        a.checked_div(b),
+        || a.saturating_div(b),
+        || a.wrapping_div(b),
+    )
+}
+
+/// Raise `base` to the power `exponent`
+pub fn pow_number(base: i64, exponent: u32, policy: OverflowPolicy) -> Result<i64, PluginError> {
+    policy.apply(
+        || format!("pow_number({base}, {exponent})"),
+        base.checked_pow(exponent),
+        || base.saturating_pow(exponent),
+        || base.wrapping_pow(exponent),
+    )
+}
+
+// Synchronous variants run directly on the calling Dart isolate, so they are only
+// added for functions whose cost is bounded by a small constant whatever the
+// arguments: no big integers, no loops over caller-sized input unless its length is
+// capped first, and no I/O. Any lock they take must only ever be held for such
+// bounded work, never across I/O, thread spawns or caller-sized work. Everything
+// else stays async.
+
+/// Longest string argument `greet_sync` accepts
+const MAX_SYNC_ARGUMENT_BYTES: usize = 1024;
+
+/// Synchronous variant of `greet` without templates or time zones
+///
+/// `name`, `locale` and the style's `prefix` and `suffix` may have at most 1 KiB each;
+/// longer ones fail with [`PluginError::InvalidArgument`], and `greet` takes them.
+#[flutter_rust_bridge::frb(sync)]
+pub fn greet_sync(
+    name: String,
+    locale: Option<String>,
+    gender: Option<Gender>,
+    style: Option<GreetingStyle>,
+) -> Result<String, PluginError> {
+    let strings = [
+        ("name", Some(&name)),
+        ("locale", locale.as_ref()),
+        ("style", style.as_ref().and_then(|s| s.prefix.as_ref())),
+        ("style", style.as_ref().and_then(|s| s.suffix.as_ref())),
+    ];
+    for (param, value) in strings {
+        if value.is_some_and(|v| v.len() > MAX_SYNC_ARGUMENT_BYTES) {
+            return Err(PluginError::invalid_argument(
+                param,
+                format!("is longer than {MAX_SYNC_ARGUMENT_BYTES} bytes; use `greet`"),
+            ));
+        }
+    }
+    greet(name, locale, gender, None, style, None, None)
+}
+
+/// Synchronous variant of `calculate_fibonacci`; at most 93 additions
+#[flutter_rust_bridge::frb(sync)]
+pub fn calculate_fibonacci_sync(n: u32) -> Result<u64, PluginError> {
+    calculate_fibonacci(n)
+}
+
+/// Synchronous variant of `add_numbers`
+#[flutter_rust_bridge::frb(sync)]
+pub fn add_numbers_sync(a: i64, b: i64, policy: OverflowPolicy) -> Result<i64, PluginError> {
+    add_numbers(a, b, policy)
+}
+
+/// Synchronous variant of `subtract_numbers`
+#[flutter_rust_bridge::frb(sync)]
+pub fn subtract_numbers_sync(a: i64, b: i64, policy: OverflowPolicy) -> Result<i64, PluginError> {
+    subtract_numbers(a, b, policy)
+}
+
+/// Synchronous variant of `multiply_numbers`
+#[flutter_rust_bridge::frb(sync)]
+pub fn multiply_numbers_sync(a: i64, b: i64, policy: OverflowPolicy) -> Result<i64, PluginError> {
+    multiply_numbers(a, b, policy)
+}
+
+/// Synchronous variant of `divide_numbers`
+#[flutter_rust_bridge::frb(sync)]
+pub fn divide_numbers_sync(a: i64, b: i64, policy: OverflowPolicy) -> Result<i64, PluginError> {
+    divide_numbers(a, b, policy)
+}
+
+/// Synchronous variant of `pow_number`
+#[flutter_rust_bridge::frb(sync)]
+pub fn pow_number_sync(
+    base: i64,
+    exponent: u32,
+    policy: OverflowPolicy,
+) -> Result<i64, PluginError> {
+    pow_number(base, exponent, policy)
 }
@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::error::PluginError;
+
+/// Emoji version supported by Android 10 and iOS 13, which most devices in use have
+const DEFAULT_EMOJI_VERSION: (u32, u32) = (12, 0);
+
+/// Decoration `greet` adds after the greeting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmojiSet {
+    None,
+    /// 🦀, the default
+    Crab,
+    /// 👋
+    Wave,
+    /// 🎉
+    Party,
+    /// ✨
+    Sparkles,
+    /// 💖
+    Heart,
+}
+
+impl EmojiSet {
+    fn emoji(self) -> &'static str {
+        match self {
+            EmojiSet::None => "",
+            EmojiSet::Crab => "🦀",
+            EmojiSet::Wave => "👋",
+            EmojiSet::Party => "🎉",
+            EmojiSet::Sparkles => "✨",
+            EmojiSet::Heart => "💖",
+        }
+    }
+
+    fn ascii(self) -> &'static str {
+        match self {
+            EmojiSet::None => "",
+            EmojiSet::Crab => r"(\/)(;,,;)(\/)",
+            EmojiSet::Wave => "o/",
+            EmojiSet::Party => r"\o/",
+            EmojiSet::Sparkles => "*",
+            EmojiSet::Heart => "<3",
+        }
+    }
+}
+
+/// Characters `greet` may produce
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TextMode {
+    /// Any Unicode, the default
+    Unicode,
+    /// Printable ASCII only, for terminals: text is transliterated (`こんにちは` becomes
+    /// `konnitiha`) and the decoration is drawn in ASCII, e.g. `o/`
+    Ascii,
+    /// No decoration, which screen readers would read out, e.g. "crab"
+    ScreenReader,
+}
+
+/// How `greet` decorates the greeting
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GreetingStyle {
+    /// `EmojiSet::Crab` when unset
+    pub emoji: Option<EmojiSet>,
+    /// `TextMode::Unicode` when unset
+    pub mode: Option<TextMode>,
+    /// Text before the greeting, as is
+    pub prefix: Option<String>,
+    /// Text after the greeting and its emoji, as is
+    pub suffix: Option<String>,
+}
+
+impl GreetingStyle {
+    /// Wrap `greeting` in the prefix, emoji and suffix, then apply the text mode
+    pub(crate) fn apply(&self, greeting: &str) -> String {
+        let mode = self.mode.unwrap_or(TextMode::Unicode);
+        let emoji = self.emoji.unwrap_or(EmojiSet::Crab);
+        let decoration = match mode {
+            TextMode::Unicode => emoji.emoji(),
+            TextMode::Ascii => emoji.ascii(),
+            TextMode::ScreenReader => "",
+        };
+        let mut text = self.prefix.clone().unwrap_or_default();
+        text.push_str(greeting);
+        if !decoration.is_empty() {
+            text.push(' ');
+            text.push_str(decoration);
+        }
+        text.push_str(self.suffix.as_deref().unwrap_or_default());
+        match mode {
+            TextMode::Ascii => deunicode::deunicode(&text),
+            _ => text,
+        }
+    }
+}
+
+/// What is known about rendering one emoji
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmojiSupport {
+    /// CLDR name, e.g. `crab`; `None` if the text is not a single emoji
+    pub name: Option<String>,
+    /// Emoji version that introduced it, e.g. `1.0`
+    pub emoji_version: Option<String>,
+    /// Whether fonts supporting `supported_version` draw it as one picture
+    pub likely_renderable: bool,
+}
+
+/// Check whether `emoji` is likely to render on a system whose fonts support emoji up
+/// to `supported_version`, e.g. `13.1`
+///
+/// Without `supported_version`, assumes 12.0 (Android 10, iOS 13). Emoji sequences
+/// newer than the fonts usually fall apart into their parts or show as boxes. Fails
+/// with [`PluginError::InvalidArgument`] if `supported_version` is not `major.minor`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn emoji_support(
+    emoji: String,
+    supported_version: Option<String>,
+) -> Result<EmojiSupport, PluginError> {
+    let supported = match supported_version {
+        Some(version) => parse_version(&version).ok_or_else(|| {
+            PluginError::invalid_argument("supported_version", "must be `major.minor`")
+        })?,
+        None => DEFAULT_EMOJI_VERSION,
+    };
+    let Some(found) = emojis::get(&emoji) else {
+        return Ok(EmojiSupport {
+            name: None,
+            emoji_version: None,
+            likely_renderable: false,
+        });
+    };
+    let version = found.unicode_version();
+    Ok(EmojiSupport {
+        name: Some(found.name().to_owned()),
+        emoji_version: Some(format!("{}.{}", version.major(), version.minor())),
+        likely_renderable: (version.major(), version.minor()) <= supported,
+    })
+}
+
+fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let (major, minor) = version.split_once('.')?;
+    Some((major.parse().ok()?, minor.parse().ok()?))
+}
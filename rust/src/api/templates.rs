@@ -0,0 +1,30 @@
+use crate::api::error::PluginError;
+use crate::templating;
+
+/// Register a greeting template that `greet` renders when passed its `name`
+///
+/// `source` uses Jinja syntax, e.g. `{{ salutation }} {{ name | title }}, good {{ time_of_day }}`,
+/// with `{% if gender == "feminine" %}...{% endif %}` conditionals and the MiniJinja
+/// filters (`upper`, `title`, `trim`, `default`, ...). Templates can use `name`,
+/// `salutation`, `greeting`, `locale`, `gender` and `time_of_day`; `greet` fails for
+/// templates that use `time_of_day` without a time zone. A template with the same name
+/// is replaced. Fails with [`PluginError::InvalidArgument`] on syntax errors
+/// and unknown variables, and on unknown filters in any branch that only depends on
+/// `gender` and `time_of_day`, since registering renders the template for each of their
+/// values.
+pub fn register_greeting_template(name: String, source: String) -> Result<(), PluginError> {
+    log::debug!(name = name.as_str(), source_len = source.len(); "register_greeting_template");
+    templating::register(name, source).map_err(|e| PluginError::invalid_argument("source", e))
+}
+
+/// Remove a greeting template; returns whether it was registered
+#[flutter_rust_bridge::frb(sync)]
+pub fn unregister_greeting_template(name: String) -> bool {
+    templating::unregister(&name)
+}
+
+/// Names of the registered greeting templates, sorted
+#[flutter_rust_bridge::frb(sync)]
+pub fn greeting_templates() -> Vec<String> {
+    templating::names()
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::error::PluginError;
+use crate::zones;
+
+/// Part of the day, which picks between "Good morning", "Good afternoon" and "Good evening"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeOfDay {
+    /// 05:00 to 11:59
+    Morning,
+    /// 12:00 to 16:59
+    Afternoon,
+    /// 17:00 to 21:59
+    Evening,
+    /// 22:00 to 04:59
+    Night,
+}
+
+impl TimeOfDay {
+    pub(crate) fn from_hour(hour: u32) -> Self {
+        match hour {
+            5..=11 => TimeOfDay::Morning,
+            12..=16 => TimeOfDay::Afternoon,
+            17..=21 => TimeOfDay::Evening,
+            _ => TimeOfDay::Night,
+        }
+    }
+
+    /// The value of `$time_of_day` in Fluent messages and `time_of_day` in greeting templates
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            TimeOfDay::Morning => "morning",
+            TimeOfDay::Afternoon => "afternoon",
+            TimeOfDay::Evening => "evening",
+            TimeOfDay::Night => "night",
+        }
+    }
+}
+
+/// The part of the day in `time_zone`, an IANA name such as `Europe/Lisbon`
+///
+/// At `now_utc_millis`, milliseconds since the Unix epoch, or at the current time
+/// without it. Uses the time zone database embedded in the crate (see
+/// `BuildInfo::tzdb_version`), so the result does not depend on the device's. Fails
+/// with [`PluginError::InvalidArgument`] for unknown time zones and instants chrono
+/// cannot represent.
+#[flutter_rust_bridge::frb(sync)]
+pub fn time_of_day(
+    time_zone: String,
+    now_utc_millis: Option<i64>,
+) -> Result<TimeOfDay, PluginError> {
+    let zone =
+        zones::parse(&time_zone).map_err(|e| PluginError::invalid_argument("time_zone", e))?;
+    let now = zones::instant(now_utc_millis)
+        .map_err(|e| PluginError::invalid_argument("now_utc_millis", e))?;
+    Ok(TimeOfDay::from_hour(zones::hour(now, zone)))
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+use crate::executor;
+
+/// Thread pool that runs an async call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkerPoolKind {
+    /// Computation; sized to the number of CPUs by default
+    Cpu,
+    /// Calls that wait on I/O or locks
+    Blocking,
+}
+
+/// Queue priority of an async call; higher priorities are started first, and a call
+/// waiting more than two seconds starts before any that arrived after it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskPriority {
+    Background,
+    Normal,
+    Interactive,
+}
+
+/// Snapshot of one worker pool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkerPoolMetrics {
+    pub pool: WorkerPoolKind,
+    /// Zero where calls run on the calling thread, as on the web without web workers
+    pub threads: u32,
+    /// Threads currently running a call
+    pub busy: u32,
+    /// Calls waiting for a free thread; non-zero means the pool is saturated
+    pub queued: u32,
+    /// Calls finished since startup
+    pub completed: u64,
+}
+
+/// Run every future call of `function` on `pool`, with `priority` unless the call sets its own
+///
+/// `function` is the Rust name, e.g. `calculate_fibonacci_big`, or `Type_method` for methods.
+/// By default short calls such as `greet` run at `Interactive` priority, long computations
+/// at `Background` priority and `init_plugin` on the blocking pool; everything else runs on
+/// the CPU pool at `Normal` priority.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_task_route(function: String, pool: WorkerPoolKind, priority: TaskPriority) {
+    executor::set_route(function, pool, priority);
+}
+
+/// Queue the next async call made from this thread at `priority`, whatever its route says
+///
+/// Applies to one call only; `None` clears a priority no call has used yet. From Dart,
+/// use `withTaskPriority`, which makes the call right away on the same thread.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_next_call_priority(priority: Option<TaskPriority>) {
+    executor::set_next_priority(priority);
+}
+
+/// Current load of the CPU and blocking pools
+#[flutter_rust_bridge::frb(sync)]
+pub fn worker_pool_metrics() -> Vec<WorkerPoolMetrics> {
+    executor::metrics()
+}
@@ -0,0 +1,311 @@
+//! Call the plugin API from a shell, without Flutter.
+//!
+//! ```text
+//! demo-native-cli [--json] [--progress] [--log-level LEVEL] <function> [--<param> <value>]...
+//! demo-native-cli list
+//! demo-native-cli serve [--socket PATH]
+//! ```
+//!
+//! `serve` answers JSON-RPC 2.0 on stdio or a Unix socket; see `daemon`.
+//!
+//! Function and parameter names are the Rust names; `-` may be used for `_`.
+
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+
+use demo_rust_native_plugin::api::cancellation::CancellationToken;
+use demo_rust_native_plugin::api::dispatch::list_functions;
+use demo_rust_native_plugin::api::error::PluginError;
+use demo_rust_native_plugin::api::init::{init_app, LogLevel};
+use demo_rust_native_plugin::api::logging::set_log_level;
+use demo_rust_native_plugin::api::progress::ProgressReporter;
+use demo_rust_native_plugin::daemon;
+use demo_rust_native_plugin::registry::{self, Context, Function, Ty};
+use serde_json::{json, Map, Value};
+
+/// Exit code for malformed command lines, as in BSD `sysexits.h`
+const EXIT_USAGE: u8 = 64;
+
+const USAGE: &str = "\
+usage: demo-native-cli [--json] [--progress] [--log-level LEVEL] <function> [--<param> <value>]...
+       demo-native-cli list
+       demo-native-cli serve [--socket PATH]
+
+Logs go to stderr at `warn` unless --log-level says otherwise.
+Lists are comma-separated, byte parameters take hex or @FILE (@- for stdin),
+and struct parameters take JSON. Run `demo-native-cli list` for the functions.";
+
+struct Options {
+    json: bool,
+    progress: bool,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(Failure::Usage(message)) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            ExitCode::from(EXIT_USAGE)
+        }
+        Err(Failure::Plugin(error, json)) => {
+            if json {
+                println!("{}", json!({ "error": registry::error_json(&error) }));
+            } else {
+                eprintln!("error: {error}");
+            }
+            ExitCode::from(error.code() as u8)
+        }
+    }
+}
+
+enum Failure {
+    Usage(String),
+    /// A failed call, and whether to report it as JSON
+    Plugin(PluginError, bool),
+}
+
+fn run(args: &[String]) -> Result<(), Failure> {
+    let mut options = Options {
+        json: false,
+        progress: false,
+    };
+    let mut log_level = LogLevel::Warn;
+    let mut rest = args;
+    while let Some(flag) = rest.first().filter(|a| a.starts_with("--")) {
+        match flag.as_str() {
+            "--json" => options.json = true,
+            "--progress" => options.progress = true,
+            "--log-level" => {
+                let level = rest
+                    .get(1)
+                    .ok_or_else(|| usage("--log-level needs a value"))?;
+                log_level = serde_json::from_value(Value::String(level.clone()))
+                    .map_err(|_| usage(format!("unknown log level `{level}`")))?;
+                rest = &rest[1..];
+            }
+            "--help" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            _ => return Err(usage(format!("unknown option `{flag}`"))),
+        }
+        rest = &rest[1..];
+    }
+    set_log_level(log_level);
+    init_app();
+    let Some((name, params)) = rest.split_first() else {
+        return Err(usage("missing function name"));
+    };
+    if name == "list" {
+        list(&options);
+        return Ok(());
+    }
+    if name == "serve" {
+        return serve(params);
+    }
+    let function = registry::find(&name.replace('-', "_"))
+        .ok_or_else(|| usage(format!("unknown function `{name}`")))?;
+    let args = parse_params(function, params)?;
+    call(function, args, &options).map_err(|e| Failure::Plugin(e, options.json))
+}
+
+/// Run the JSON-RPC server on stdio, or on a Unix socket with `--socket PATH`
+fn serve(params: &[String]) -> Result<(), Failure> {
+    let result = match params {
+        [] => daemon::serve_stdio(),
+        #[cfg(unix)]
+        [flag, path] if flag == "--socket" => daemon::serve_unix(std::path::Path::new(path)),
+        _ => return Err(usage("serve takes no options besides `--socket PATH`")),
+    };
+    result.map_err(|e| {
+        Failure::Plugin(
+            PluginError::Internal {
+                details: e.to_string(),
+            },
+            false,
+        )
+    })
+}
+
+fn usage(message: impl Into<String>) -> Failure {
+    Failure::Usage(message.into())
+}
+
+fn list(options: &Options) {
+    if options.json {
+        println!("{}", json!(list_functions()));
+        return;
+    }
+    for f in registry::functions() {
+        let params: Vec<String> = f
+            .params
+            .iter()
+            .map(|p| format!("{}: {}", p.name, p.ty))
+            .collect();
+        let stream = f
+            .stream
+            .map_or(String::new(), |ty| format!(", streams {ty}"));
+        println!("{}({}) -> {}{stream}", f.name, params.join(", "), f.returns);
+    }
+}
+
+/// Turn `--name value` pairs into JSON arguments according to the parameter types
+fn parse_params(function: &Function, params: &[String]) -> Result<Map<String, Value>, Failure> {
+    let mut args = Map::new();
+    let mut rest = params;
+    while let Some((flag, tail)) = rest.split_first() {
+        let Some(flag) = flag.strip_prefix("--") else {
+            return Err(usage(format!("expected `--<param>`, found `{flag}`")));
+        };
+        let (key, text) = match flag.split_once('=') {
+            Some((key, text)) => (key, text.to_owned()),
+            None => {
+                let text = tail
+                    .first()
+                    .ok_or_else(|| usage(format!("--{flag} needs a value")))?;
+                rest = tail;
+                (flag, text.clone())
+            }
+        };
+        rest = &rest[1..];
+        let key = key.replace('-', "_");
+        let param = function
+            .params
+            .iter()
+            .find(|p| p.name == key)
+            .ok_or_else(|| usage(format!("{} has no parameter `{key}`", function.name)))?;
+        let value = parse_value(&param.ty, &text).map_err(|e| usage(format!("--{flag}: {e}")))?;
+        args.insert(key, value);
+    }
+    Ok(args)
+}
+
+fn parse_value(ty: &Ty, text: &str) -> Result<Value, String> {
+    match ty {
+        Ty::Int(_) | Ty::Float => text
+            .parse::<serde_json::Number>()
+            .map(Value::Number)
+            .map_err(|_| format!("`{text}` is not a number")),
+        Ty::Bool => text
+            .parse()
+            .map(Value::Bool)
+            .map_err(|_| format!("`{text}` is not true or false")),
+        Ty::String | Ty::Enum(..) => Ok(Value::String(text.to_owned())),
+        Ty::Bytes => read_bytes(text).map(|bytes| json!(bytes)),
+        Ty::List(item) => text
+            .split(',')
+            .filter(|part| !part.is_empty())
+            .map(|part| parse_value(item, part.trim()))
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+        Ty::Optional(inner) => parse_value(inner, text),
+        Ty::Struct(name) => {
+            serde_json::from_str(text).map_err(|e| format!("expected {name} as JSON: {e}"))
+        }
+        Ty::Unit => Err("takes no value".to_owned()),
+    }
+}
+
+/// Hex digits, or the contents of `@FILE` (`@-` for stdin)
+fn read_bytes(text: &str) -> Result<Vec<u8>, String> {
+    match text.strip_prefix('@') {
+        Some("-") => {
+            let mut bytes = Vec::new();
+            io::stdin()
+                .read_to_end(&mut bytes)
+                .map_err(|e| e.to_string())?;
+            Ok(bytes)
+        }
+        Some(path) => std::fs::read(path).map_err(|e| format!("{path}: {e}")),
+        None if !text.bytes().all(|b| b.is_ascii_hexdigit()) => Err(format!("`{text}` is not hex")),
+        None if !text.len().is_multiple_of(2) => {
+            Err(format!("`{text}` has an odd number of hex digits"))
+        }
+        // Only ASCII hex digits remain, so every pair is a valid `str`
+        None => Ok(text
+            .as_bytes()
+            .chunks(2)
+            .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).unwrap(), 16).unwrap())
+            .collect()),
+    }
+}
+
+fn call(
+    function: &Function,
+    args: Map<String, Value>,
+    options: &Options,
+) -> Result<(), PluginError> {
+    let cancellation = CancellationToken::new();
+    let progress = ProgressReporter::new(100);
+    if options.progress {
+        progress.listen(|update| {
+            let percent = update.fraction * 100.0;
+            match update.message {
+                Some(message) => eprintln!("progress: {percent:.0}% {message}"),
+                None => eprintln!("progress: {percent:.0}%"),
+            }
+            true
+        });
+    }
+    let json = options.json;
+    let stream_ty = function.stream.unwrap_or(Ty::Unit);
+    let mut emit = |item: Value| {
+        let line = if json {
+            json!({ "stream": item }).to_string()
+        } else {
+            render(&item, &stream_ty)
+        };
+        // Stop streaming once stdout is closed, e.g. piped into `head`
+        writeln!(io::stdout(), "{line}").is_ok()
+    };
+    let result = function.call(
+        args,
+        &mut Context {
+            cancellation: &cancellation,
+            progress: &progress,
+            emit: &mut emit,
+        },
+    )?;
+    if json {
+        println!("{}", json!({ "result": result }));
+    } else if function.returns != Ty::Unit {
+        println!("{}", render(&result, &function.returns));
+    }
+    Ok(())
+}
+
+/// Human-readable form of a result or stream item
+fn render(value: &Value, ty: &Ty) -> String {
+    match (value, ty) {
+        (Value::Null, _) => "none".to_owned(),
+        (Value::String(s), _) => s.clone(),
+        (Value::Array(bytes), Ty::Bytes) => bytes
+            .iter()
+            .map(|b| format!("{:02x}", b.as_u64().unwrap_or_default()))
+            .collect(),
+        (Value::Array(items), _) if items.iter().all(|v| !v.is_object() && !v.is_array()) => {
+            let item_ty = match ty {
+                Ty::List(item) => item,
+                _ => &Ty::Unit,
+            };
+            let items: Vec<String> = items.iter().map(|v| render(v, item_ty)).collect();
+            items.join(", ")
+        }
+        (Value::Array(items), _) => {
+            let items: Vec<String> = items.iter().map(|v| render(v, &Ty::Unit)).collect();
+            items.join("\n\n")
+        }
+        (Value::Object(fields), _) => {
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|(key, value)| match value {
+                    Value::Object(_) => format!("{key}: {value}"),
+                    _ => format!("{key}: {}", render(value, &Ty::Unit)),
+                })
+                .collect();
+            fields.join("\n")
+        }
+        (other, _) => other.to_string(),
+    }
+}
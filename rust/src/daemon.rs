@@ -0,0 +1,367 @@
+//! JSON-RPC 2.0 server for the functions in [`crate::registry`].
+//!
+//! Messages are newline-delimited JSON. Methods are the registry's Rust names and
+//! take their arguments by name or by position. Besides responses, the server sends
+//! these notifications, each naming the request in `params.id`:
+//!
+//! - `stream` with `item`, once per item of a streaming function
+//! - `progress` with `fraction` and `message`, from long-running functions
+//!
+//! Clients cancel a running request with the `$/cancel` notification and
+//! `{"id": <request id>}`. Plugin failures use `PluginError::code()` as the error
+//! code, with the serialized error as `data`.
+//!
+//! Requests run on the worker pools, routed like calls from Dart. A connection with
+//! `MAX_IN_FLIGHT` messages running gets `SERVER_BUSY` errors for further ones.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Condvar, Mutex};
+
+use serde_json::{json, Map, Value};
+
+use crate::api::cancellation::CancellationToken;
+use crate::api::progress::ProgressReporter;
+use crate::executor;
+use crate::registry::{self, Context};
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+const SERVER_BUSY: i64 = -32000;
+
+/// Messages, single or batch, one connection may have running at once
+pub const MAX_IN_FLIGHT: usize = 64;
+
+/// Minimum time between `progress` notifications for one request
+const PROGRESS_INTERVAL_MS: u32 = 100;
+
+/// Where a connection's responses and notifications go
+pub type Output = Arc<Mutex<dyn Write + Send>>;
+
+/// State shared by the requests of one connection
+struct Connection {
+    output: Output,
+    running: Mutex<HashMap<String, Arc<CancellationToken>>>,
+    in_flight: Mutex<usize>,
+    idle: Condvar,
+}
+
+impl Connection {
+    /// Count a message as running; `false` if the connection is at `MAX_IN_FLIGHT`
+    fn begin(&self) -> bool {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if *in_flight == MAX_IN_FLIGHT {
+            return false;
+        }
+        *in_flight += 1;
+        true
+    }
+
+    /// The cancellation token of `request`, registered before it is queued so that a
+    /// `$/cancel` read right after it finds it
+    fn register(&self, request: &Value) -> Arc<CancellationToken> {
+        let token = Arc::new(CancellationToken::new());
+        if let Some(id) = request.get("id") {
+            self.running
+                .lock()
+                .unwrap()
+                .insert(id.to_string(), Arc::clone(&token));
+        }
+        token
+    }
+
+    fn end(&self) {
+        *self.in_flight.lock().unwrap() -= 1;
+        self.idle.notify_all();
+    }
+
+    fn wait_idle(&self) {
+        let in_flight = self.in_flight.lock().unwrap();
+        drop(self.idle.wait_while(in_flight, |n| *n > 0).unwrap());
+    }
+}
+
+/// Serve requests from stdin until it is closed, answering on stdout
+pub fn serve_stdio() -> io::Result<()> {
+    serve_connection(io::stdin().lock(), Arc::new(Mutex::new(io::stdout())))
+}
+
+/// Serve every client that connects to a Unix socket at `path`, until the process exits
+///
+/// Fails if another server is already listening there; a stale socket file is replaced.
+#[cfg(unix)]
+pub fn serve_unix(path: &std::path::Path) -> io::Result<()> {
+    use std::io::BufReader;
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::thread;
+
+    if path.exists() {
+        if UnixStream::connect(path).is_ok() {
+            return Err(io::Error::new(
+                io::ErrorKind::AddrInUse,
+                format!("a server is already listening on {}", path.display()),
+            ));
+        }
+        std::fs::remove_file(path)?;
+    }
+    let listener = UnixListener::bind(path)?;
+    log::info!("serving JSON-RPC on {}", path.display());
+    for stream in listener.incoming() {
+        let stream = stream?;
+        thread::spawn(move || {
+            let result = stream.try_clone().and_then(|writer| {
+                serve_connection(BufReader::new(stream), Arc::new(Mutex::new(writer)))
+            });
+            if let Err(e) = result {
+                log::warn!("JSON-RPC connection failed: {e}");
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Handle one client; returns once its input ends and every request has been answered
+pub fn serve_connection(input: impl BufRead, output: Output) -> io::Result<()> {
+    let connection = Arc::new(Connection {
+        output,
+        running: Mutex::default(),
+        in_flight: Mutex::new(0),
+        idle: Condvar::new(),
+    });
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let message = match serde_json::from_str::<Value>(&line) {
+            Ok(message) => message,
+            Err(e) => {
+                send(
+                    &connection.output,
+                    &failure(Value::Null, PARSE_ERROR, e.to_string(), None),
+                );
+                continue;
+            }
+        };
+        // Cancelling takes no time and must work even when the connection is busy.
+        if method(&message) == Some("$/cancel") {
+            if let Some(response) = handle(message, &connection, &CancellationToken::new()) {
+                send(&connection.output, &response);
+            }
+            continue;
+        }
+        if !connection.begin() {
+            let busy = |request: &Value| {
+                let id = request.get("id")?.clone();
+                let message = format!("{MAX_IN_FLIGHT} requests are already running");
+                Some(failure(id, SERVER_BUSY, message, None))
+            };
+            match &message {
+                Value::Array(batch) => {
+                    let responses: Vec<Value> = batch.iter().filter_map(busy).collect();
+                    if !responses.is_empty() {
+                        send(&connection.output, &Value::Array(responses));
+                    }
+                }
+                request => {
+                    if let Some(response) = busy(request) {
+                        send(&connection.output, &response);
+                    }
+                }
+            }
+            continue;
+        }
+        match message {
+            Value::Array(batch) if !batch.is_empty() => {
+                // Members of a batch run concurrently; the last to finish sends every response.
+                let pending = Arc::new(Mutex::new((Vec::new(), batch.len())));
+                for request in batch {
+                    let connection = Arc::clone(&connection);
+                    let pending = Arc::clone(&pending);
+                    let function = method(&request).unwrap_or_default().to_owned();
+                    let cancellation = connection.register(&request);
+                    executor::spawn(&function, move || {
+                        let response = handle_caught(request, &connection, cancellation);
+                        let mut pending = pending.lock().unwrap();
+                        pending.0.extend(response);
+                        pending.1 -= 1;
+                        if pending.1 == 0 {
+                            let responses = std::mem::take(&mut pending.0);
+                            if !responses.is_empty() {
+                                send(&connection.output, &Value::Array(responses));
+                            }
+                            connection.end();
+                        }
+                    });
+                }
+            }
+            request => {
+                let connection = Arc::clone(&connection);
+                let function = method(&request).unwrap_or_default().to_owned();
+                let cancellation = connection.register(&request);
+                executor::spawn(&function, move || {
+                    if let Some(response) = handle_caught(request, &connection, cancellation) {
+                        send(&connection.output, &response);
+                    }
+                    connection.end();
+                });
+            }
+        }
+    }
+    connection.wait_idle();
+    Ok(())
+}
+
+fn method(message: &Value) -> Option<&str> {
+    message.get("method")?.as_str()
+}
+
+/// `handle` with `cancellation` from `Connection::register`, answering with an internal
+/// error if the function panics
+fn handle_caught(
+    request: Value,
+    connection: &Connection,
+    cancellation: Arc<CancellationToken>,
+) -> Option<Value> {
+    let id = request.get("id").cloned();
+    let response = panic::catch_unwind(AssertUnwindSafe(|| {
+        handle(request, connection, &cancellation)
+    }));
+    if let Some(id) = &id {
+        let mut running = connection.running.lock().unwrap();
+        // A later request may have reused the id.
+        if running
+            .get(&id.to_string())
+            .is_some_and(|token| Arc::ptr_eq(token, &cancellation))
+        {
+            running.remove(&id.to_string());
+        }
+    }
+    response.unwrap_or_else(|_| {
+        let id = id?;
+        Some(failure(id, INTERNAL_ERROR, "the function panicked", None))
+    })
+}
+
+/// Run one request; returns its response, or `None` for a notification
+fn handle(
+    request: Value,
+    connection: &Connection,
+    cancellation: &CancellationToken,
+) -> Option<Value> {
+    let Connection {
+        output, running, ..
+    } = connection;
+    let Value::Object(mut request) = request else {
+        return Some(failure(
+            Value::Null,
+            INVALID_REQUEST,
+            "expected an object",
+            None,
+        ));
+    };
+    let id = request.remove("id");
+    let respond = |result: Result<Value, (i64, String, Option<Value>)>| {
+        let id = id.clone()?;
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err((code, message, data)) => failure(id, code, message, data),
+        })
+    };
+    // Invalid requests are answered even without an id, as the spec requires.
+    let invalid = |message: &str| {
+        let id = id.clone().unwrap_or(Value::Null);
+        Some(failure(id, INVALID_REQUEST, message, None))
+    };
+    let Some(Value::String(method)) = request.remove("method") else {
+        return invalid("missing method");
+    };
+    if request.get("jsonrpc") != Some(&json!("2.0")) {
+        return invalid("jsonrpc must be \"2.0\"");
+    }
+    let params = request.remove("params").unwrap_or(Value::Null);
+
+    if method == "$/cancel" {
+        if let Some(target) = params.get("id") {
+            if let Some(token) = running.lock().unwrap().get(&target.to_string()) {
+                token.cancel();
+            }
+        }
+        return respond(Ok(Value::Null));
+    }
+    let Some(function) = registry::find(&method) else {
+        return respond(Err((
+            METHOD_NOT_FOUND,
+            format!("unknown method `{method}`"),
+            None,
+        )));
+    };
+    let args = match params {
+        Value::Null => Map::new(),
+        Value::Object(args) => args,
+        Value::Array(values) if values.len() <= function.params.len() => function
+            .params
+            .iter()
+            .map(|p| p.name.to_owned())
+            .zip(values)
+            .collect(),
+        _ => {
+            let message = format!("{method} takes {} parameters", function.params.len());
+            return respond(Err((INVALID_PARAMS, message, None)));
+        }
+    };
+
+    let request_id = id.clone().unwrap_or(Value::Null);
+    let progress = ProgressReporter::new(PROGRESS_INTERVAL_MS);
+    progress.listen({
+        let output = Arc::clone(output);
+        let request_id = request_id.clone();
+        move |update| {
+            notify(
+                &output,
+                "progress",
+                json!({ "id": request_id, "fraction": update.fraction, "message": update.message }),
+            )
+        }
+    });
+    let mut emit = |item| notify(output, "stream", json!({ "id": request_id, "item": item }));
+    let result = function.call(
+        args,
+        &mut Context {
+            cancellation,
+            progress: &progress,
+            emit: &mut emit,
+        },
+    );
+    respond(result.map_err(|error| {
+        let code = i64::from(error.code());
+        (code, error.message(), Some(registry::error_json(&error)))
+    }))
+}
+
+fn failure(id: Value, code: i64, message: impl Into<String>, data: Option<Value>) -> Value {
+    let mut error = json!({ "code": code, "message": message.into() });
+    if let Some(data) = data {
+        error["data"] = data;
+    }
+    json!({ "jsonrpc": "2.0", "id": id, "error": error })
+}
+
+/// Send a notification; returns `false` once the client has gone away
+fn notify(output: &Output, method: &str, params: Value) -> bool {
+    send(
+        output,
+        &json!({ "jsonrpc": "2.0", "method": method, "params": params }),
+    )
+}
+
+fn send(output: &Output, message: &Value) -> bool {
+    let mut output = output.lock().unwrap();
+    writeln!(output, "{message}")
+        .and_then(|()| output.flush())
+        .is_ok()
+}
@@ -0,0 +1,442 @@
+use std::cell::Cell;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Condvar, LazyLock, Mutex, RwLock};
+use std::time::Duration;
+use std::{io, thread};
+
+use flutter_rust_bridge::for_generated::{
+    BaseCodec, Executor, NoOpErrorListener, SimpleExecutor, SimpleHandler, TaskContext, TaskInfo,
+    TaskRetFutTrait,
+};
+#[cfg(target_family = "wasm")]
+use flutter_rust_bridge::{for_generated::TransferClosure, SimpleThreadPool};
+use flutter_rust_bridge::{BaseThreadPool, SimpleAsyncRuntime};
+use web_time::Instant;
+
+use crate::api::workers::{TaskPriority, WorkerPoolKind, WorkerPoolMetrics};
+use crate::frb_generated::FLUTTER_RUST_BRIDGE_CODEGEN_VERSION;
+
+/// Threads in the blocking pool unless configured otherwise
+const DEFAULT_BLOCKING_THREADS: usize = 4;
+
+/// Most threads a pool may be configured with
+pub(crate) const MAX_POOL_THREADS: u32 = 256;
+
+/// A call queued this long starts next whatever its priority, so that a steady stream
+/// of higher-priority calls cannot hold it back forever
+const STARVATION_LIMIT: Duration = Duration::from_secs(2);
+
+pub(crate) static FLUTTER_RUST_BRIDGE_HANDLER: LazyLock<
+    SimpleHandler<PluginExecutor, NoOpErrorListener>,
+> = LazyLock::new(|| {
+    assert_eq!(
+        FLUTTER_RUST_BRIDGE_CODEGEN_VERSION,
+        flutter_rust_bridge::for_generated::FLUTTER_RUST_BRIDGE_RUNTIME_VERSION,
+        "Please ensure flutter_rust_bridge's codegen and runtime versions are the same",
+    );
+    SimpleHandler::new(
+        PluginExecutor(SimpleExecutor::new(
+            NoOpErrorListener,
+            PoolRouter,
+            Default::default(),
+        )),
+        NoOpErrorListener,
+    )
+});
+
+static POOLS: LazyLock<WorkerPools> = LazyLock::new(|| WorkerPools {
+    cpu: Pool::new("demo-native-cpu", default_cpu_threads()),
+    blocking: Pool::new("demo-native-io", DEFAULT_BLOCKING_THREADS),
+});
+
+/// Where a call runs; looked up by the function's debug name
+#[derive(Debug, Clone, Copy)]
+struct Route {
+    pool: WorkerPoolKind,
+    /// Unused on the web, where no call waits in a queue
+    #[cfg_attr(target_family = "wasm", allow(dead_code))]
+    priority: TaskPriority,
+}
+
+impl Default for Route {
+    fn default() -> Self {
+        Self {
+            pool: WorkerPoolKind::Cpu,
+            priority: TaskPriority::Normal,
+        }
+    }
+}
+
+static ROUTES: LazyLock<RwLock<HashMap<String, Route>>> = LazyLock::new(|| {
+    let background = Route {
+        pool: WorkerPoolKind::Cpu,
+        priority: TaskPriority::Background,
+    };
+    let interactive = Route {
+        pool: WorkerPoolKind::Cpu,
+        priority: TaskPriority::Interactive,
+    };
+    let io = Route {
+        pool: WorkerPoolKind::Blocking,
+        priority: TaskPriority::Normal,
+    };
+    RwLock::new(HashMap::from([
+        ("add_numbers".to_owned(), interactive),
+        ("calculate_fibonacci".to_owned(), interactive),
+        ("calculate_fibonacci_big".to_owned(), background),
+        ("fibonacci_sequence".to_owned(), background),
+        ("greet".to_owned(), interactive),
+        ("init_plugin".to_owned(), io),
+    ]))
+});
+
+thread_local! {
+    /// Route of the call being dispatched on this thread, handed from the executor to the pool
+    static NEXT_ROUTE: Cell<Option<Route>> = const { Cell::new(None) };
+
+    /// Priority of the next async call made from this thread, set by `set_next_call_priority`
+    static NEXT_PRIORITY: Cell<Option<TaskPriority>> = const { Cell::new(None) };
+}
+
+#[cfg(target_family = "wasm")]
+thread_local! {
+    /// Web workers for normal calls, created on first use when `web_workers_available`
+    static WEB_WORKERS: SimpleThreadPool = SimpleThreadPool::default();
+}
+
+/// `SimpleExecutor` that tags each normal call with its route before queueing it
+pub(crate) struct PluginExecutor(SimpleExecutor<NoOpErrorListener, PoolRouter, SimpleAsyncRuntime>);
+
+impl Executor for PluginExecutor {
+    fn execute_normal<Rust2DartCodec, TaskFn>(&self, task_info: TaskInfo, task: TaskFn)
+    where
+        TaskFn: FnOnce(TaskContext) -> Result<Rust2DartCodec::Message, Rust2DartCodec::Message>
+            + Send
+            + 'static,
+        Rust2DartCodec: BaseCodec,
+    {
+        let mut route = ROUTES
+            .read()
+            .unwrap()
+            .get(task_info.debug_name)
+            .copied()
+            .unwrap_or_default();
+        if let Some(priority) = NEXT_PRIORITY.take() {
+            route.priority = priority;
+        }
+        #[cfg(target_family = "wasm")]
+        if !web_workers_available() {
+            // No threads: run the call on this thread once it is idle.
+            let pool = POOLS.get(route.pool);
+            return self
+                .0
+                .execute_async::<Rust2DartCodec, _, _>(task_info, move |context| {
+                    std::future::ready(pool.run(|| task(context)))
+                });
+        }
+        NEXT_ROUTE.set(Some(route));
+        self.0.execute_normal::<Rust2DartCodec, _>(task_info, task);
+    }
+
+    fn execute_sync<Rust2DartCodec, SyncTaskFn>(
+        &self,
+        task_info: TaskInfo,
+        sync_task: SyncTaskFn,
+    ) -> Rust2DartCodec::Message
+    where
+        SyncTaskFn: FnOnce() -> Result<Rust2DartCodec::Message, Rust2DartCodec::Message>,
+        Rust2DartCodec: BaseCodec,
+    {
+        self.0
+            .execute_sync::<Rust2DartCodec, _>(task_info, sync_task)
+    }
+
+    fn execute_async<Rust2DartCodec, TaskFn, TaskRetFut>(&self, task_info: TaskInfo, task: TaskFn)
+    where
+        TaskFn: FnOnce(TaskContext) -> TaskRetFut + Send + 'static,
+        TaskRetFut: Future<Output = Result<Rust2DartCodec::Message, Rust2DartCodec::Message>>
+            + TaskRetFutTrait,
+        Rust2DartCodec: BaseCodec,
+    {
+        self.0
+            .execute_async::<Rust2DartCodec, _, _>(task_info, task);
+    }
+}
+
+struct WorkerPools {
+    cpu: Pool,
+    blocking: Pool,
+}
+
+impl WorkerPools {
+    fn get(&self, kind: WorkerPoolKind) -> &Pool {
+        match kind {
+            WorkerPoolKind::Cpu => &self.cpu,
+            WorkerPoolKind::Blocking => &self.blocking,
+        }
+    }
+}
+
+/// Thread pool handed to `SimpleExecutor`; forwards each job to the pool its route names
+pub(crate) struct PoolRouter;
+
+#[cfg(not(target_family = "wasm"))]
+impl BaseThreadPool for PoolRouter {
+    fn execute<F>(&self, job: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let route = NEXT_ROUTE.take().unwrap_or_default();
+        POOLS.get(route.pool).execute(route.priority, Box::new(job));
+    }
+}
+
+/// On the web a job can only move to another thread as a message to a web worker, so
+/// calls sent to workers skip the pools, their priorities and their metrics.
+#[cfg(target_family = "wasm")]
+impl BaseThreadPool for PoolRouter {
+    fn execute(&self, closure: TransferClosure<wasm_bindgen::JsValue>) {
+        NEXT_ROUTE.take();
+        WEB_WORKERS
+            .with(|workers| workers.execute(closure))
+            .unwrap();
+    }
+}
+
+/// Whether web workers can run calls: this needs a build with atomics and a
+/// cross-origin isolated page, as `SharedArrayBuffer` does
+#[cfg(target_family = "wasm")]
+fn web_workers_available() -> bool {
+    cfg!(target_feature = "atomics")
+        && js_sys::Reflect::get(&js_sys::global(), &"crossOriginIsolated".into())
+            .is_ok_and(|isolated| isolated.is_truthy())
+}
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+struct QueuedJob {
+    queued_at: Instant,
+    job: Job,
+}
+
+/// One first-in-first-out queue per priority, lowest first
+#[derive(Default)]
+struct JobQueue([VecDeque<QueuedJob>; 3]);
+
+impl JobQueue {
+    fn push(&mut self, priority: TaskPriority, job: Job) {
+        self.0[priority as usize].push_back(QueuedJob {
+            queued_at: Instant::now(),
+            job,
+        });
+    }
+
+    /// The oldest call that has waited past `STARVATION_LIMIT`, else the oldest call of
+    /// the highest priority
+    fn pop(&mut self) -> Option<Job> {
+        let now = Instant::now();
+        let starving = self
+            .0
+            .iter()
+            .enumerate()
+            .filter_map(|(index, queue)| Some((queue.front()?.queued_at, index)))
+            .filter(|(queued_at, _)| now.duration_since(*queued_at) >= STARVATION_LIMIT)
+            .min();
+        let index = match starving {
+            Some((_, index)) => index,
+            None => self.0.iter().rposition(|queue| !queue.is_empty())?,
+        };
+        self.0[index].pop_front().map(|queued| queued.job)
+    }
+
+    fn len(&self) -> usize {
+        self.0.iter().map(VecDeque::len).sum()
+    }
+}
+
+/// Fixed-size pool of named threads serving a priority queue
+///
+/// Where threads cannot be spawned, as on wasm32-unknown-unknown, the pool has none
+/// and runs each call on the thread that queues it.
+struct Pool {
+    name: &'static str,
+    shared: Arc<Shared>,
+}
+
+struct Shared {
+    state: Mutex<PoolState>,
+    job_available: Condvar,
+}
+
+#[derive(Default)]
+struct PoolState {
+    queue: JobQueue,
+    target_threads: usize,
+    threads: usize,
+    spawned: usize,
+    busy: usize,
+    completed: u64,
+}
+
+impl Pool {
+    fn new(name: &'static str, threads: usize) -> Self {
+        let pool = Self {
+            name,
+            shared: Arc::new(Shared {
+                state: Mutex::default(),
+                job_available: Condvar::new(),
+            }),
+        };
+        if let Err(e) = pool.resize(threads) {
+            log::error!("{name}: {e}");
+        }
+        pool
+    }
+
+    // Unused on the web, where calls run inline or go to web workers
+    #[cfg_attr(target_family = "wasm", allow(dead_code))]
+    fn execute(&self, priority: TaskPriority, job: Job) {
+        let mut state = self.shared.state.lock().unwrap();
+        if state.threads == 0 {
+            drop(state);
+            self.run(job);
+            return;
+        }
+        state.queue.push(priority, job);
+        drop(state);
+        self.shared.job_available.notify_one();
+    }
+
+    /// Run `f` on the calling thread, counted as a call of this pool
+    fn run<R>(&self, f: impl FnOnce() -> R) -> R {
+        self.shared.state.lock().unwrap().busy += 1;
+        let result = f();
+        let mut state = self.shared.state.lock().unwrap();
+        state.busy -= 1;
+        state.completed += 1;
+        result
+    }
+
+    /// Grow right away; surplus threads exit once they finish their current call
+    ///
+    /// Threads are spawned without holding the pool's lock. If one cannot be spawned, the
+    /// pool keeps the threads it has and the error is returned.
+    fn resize(&self, threads: usize) -> io::Result<()> {
+        self.shared.state.lock().unwrap().target_threads = threads;
+        self.shared.job_available.notify_all();
+        loop {
+            let index = {
+                let mut state = self.shared.state.lock().unwrap();
+                if state.threads >= state.target_threads {
+                    return Ok(());
+                }
+                // Counted before it starts, so that it does not exit as surplus
+                state.threads += 1;
+                state.spawned += 1;
+                state.spawned - 1
+            };
+            let shared = Arc::clone(&self.shared);
+            let spawned = thread::Builder::new()
+                .name(format!("{}-{index}", self.name))
+                .spawn(move || worker_loop(&shared));
+            if let Err(e) = spawned {
+                let mut state = self.shared.state.lock().unwrap();
+                state.threads -= 1;
+                state.target_threads = state.threads;
+                if e.kind() == io::ErrorKind::Unsupported {
+                    log::debug!(
+                        "{}: no threads on this platform, running calls inline",
+                        self.name
+                    );
+                    return Ok(());
+                }
+                return Err(e);
+            }
+        }
+    }
+
+    fn metrics(&self, kind: WorkerPoolKind) -> WorkerPoolMetrics {
+        let state = self.shared.state.lock().unwrap();
+        WorkerPoolMetrics {
+            pool: kind,
+            // Running inline means no threads at all; otherwise surplus threads are on their way out.
+            threads: if state.threads == 0 {
+                0
+            } else {
+                state.target_threads as u32
+            },
+            busy: state.busy as u32,
+            queued: state.queue.len() as u32,
+            completed: state.completed,
+        }
+    }
+}
+
+fn worker_loop(shared: &Shared) {
+    loop {
+        let job = {
+            let mut state = shared.state.lock().unwrap();
+            loop {
+                if state.threads > state.target_threads {
+                    state.threads -= 1;
+                    return;
+                }
+                if let Some(job) = state.queue.pop() {
+                    state.busy += 1;
+                    break job;
+                }
+                state = shared.job_available.wait(state).unwrap();
+            }
+        };
+        // The executor catches panics inside the job, so the worker survives them.
+        job();
+        let mut state = shared.state.lock().unwrap();
+        state.busy -= 1;
+        state.completed += 1;
+    }
+}
+
+/// Resize a pool; `None` restores its default size
+pub(crate) fn set_pool_threads(kind: WorkerPoolKind, threads: Option<usize>) -> io::Result<()> {
+    let threads = threads.unwrap_or_else(|| match kind {
+        WorkerPoolKind::Cpu => default_cpu_threads(),
+        WorkerPoolKind::Blocking => DEFAULT_BLOCKING_THREADS,
+    });
+    POOLS.get(kind).resize(threads)
+}
+
+pub(crate) fn set_route(function: String, pool: WorkerPoolKind, priority: TaskPriority) {
+    ROUTES
+        .write()
+        .unwrap()
+        .insert(function, Route { pool, priority });
+}
+
+/// Run `job` on the pool and at the priority of calls to `function`
+#[cfg(feature = "cli")]
+pub(crate) fn spawn(function: &str, job: impl FnOnce() + Send + 'static) {
+    let route = ROUTES
+        .read()
+        .unwrap()
+        .get(function)
+        .copied()
+        .unwrap_or_default();
+    POOLS.get(route.pool).execute(route.priority, Box::new(job));
+}
+
+pub(crate) fn set_next_priority(priority: Option<TaskPriority>) {
+    NEXT_PRIORITY.set(priority);
+}
+
+pub(crate) fn metrics() -> Vec<WorkerPoolMetrics> {
+    vec![
+        POOLS.cpu.metrics(WorkerPoolKind::Cpu),
+        POOLS.blocking.metrics(WorkerPoolKind::Blocking),
+    ]
+}
+
+fn default_cpu_threads() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
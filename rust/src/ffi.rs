@@ -0,0 +1,191 @@
+//! Versioned C API over `api::simple` for consumers outside Flutter.
+//!
+//! The header `include/demo_rust_native_plugin.h` is generated from this file
+//! by `build.rs` and committed; `tests/generated.rs` checks it is current.
+//! Rules for callers:
+//!
+//! - Every function except the version and string helpers returns a `Status`;
+//!   results are written through `out` only on `DEMO_RUST_NATIVE_PLUGIN_STATUS_OK`.
+//! - Input strings are borrowed, NUL-terminated UTF-8.
+//! - Returned strings belong to the caller and must be released with
+//!   `demo_rust_native_plugin_free_string`.
+//! - After a failure, `demo_rust_native_plugin_last_error_message` describes
+//!   the most recent error on the calling thread.
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::api::error::PluginError;
+use crate::api::simple;
+
+/// Bumped whenever a function or type in this API changes incompatibly
+pub const ABI_VERSION: u32 = 1;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Result of a C API call; failure codes equal `PluginError::code()`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok = 0,
+    Overflow = 1,
+    InvalidArgument = 2,
+    Cancelled = 3,
+    Internal = 4,
+}
+
+impl From<&PluginError> for Status {
+    fn from(error: &PluginError) -> Self {
+        match error {
+            PluginError::Overflow { .. } => Status::Overflow,
+            PluginError::InvalidArgument { .. } => Status::InvalidArgument,
+            PluginError::Cancelled => Status::Cancelled,
+            PluginError::Internal { .. } => Status::Internal,
+        }
+    }
+}
+
+/// How `demo_rust_native_plugin_add_numbers` handles results that do not fit in `int64_t`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    Checked = 0,
+    Saturating = 1,
+    Wrapping = 2,
+}
+
+/// Version of this API; compare with `DEMO_RUST_NATIVE_PLUGIN_ABI_VERSION`
+#[no_mangle]
+pub extern "C" fn demo_rust_native_plugin_abi_version() -> u32 {
+    ABI_VERSION
+}
+
+/// Write the greeting for `name` to `*out`
+///
+/// # Safety
+///
+/// `name` must be a NUL-terminated string and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn demo_rust_native_plugin_greet(
+    name: *const c_char,
+    out: *mut *mut c_char,
+) -> Status {
+    call(out, || {
+        let name = borrow_str(name, "name")?;
+        let greeting = simple::greet(name.to_owned(), None, None, None, None, None, None)?;
+        // A NUL in `name` would have ended it early, so the greeting has none.
+        Ok(CString::new(greeting).unwrap().into_raw())
+    })
+}
+
+/// Write `F(n)` to `*out`; fails with an overflow status for `n > 93`
+///
+/// # Safety
+///
+/// `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn demo_rust_native_plugin_calculate_fibonacci(
+    n: u32,
+    out: *mut u64,
+) -> Status {
+    call(out, || simple::calculate_fibonacci(n))
+}
+
+/// Write `a + b` to `*out`, handling overflow according to `policy`
+///
+/// `policy` is a `DemoRustNativePluginOverflowPolicy` value; others fail with an
+/// invalid argument status.
+///
+/// # Safety
+///
+/// `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn demo_rust_native_plugin_add_numbers(
+    a: i64,
+    b: i64,
+    policy: u32,
+    out: *mut i64,
+) -> Status {
+    call(out, || {
+        // Taken as an integer: an out-of-range value in a Rust enum would be undefined behavior.
+        let policy = match policy {
+            p if p == OverflowPolicy::Checked as u32 => simple::OverflowPolicy::Checked,
+            p if p == OverflowPolicy::Saturating as u32 => simple::OverflowPolicy::Saturating,
+            p if p == OverflowPolicy::Wrapping as u32 => simple::OverflowPolicy::Wrapping,
+            p => {
+                return Err(PluginError::invalid_argument(
+                    "policy",
+                    format!("{p} is not an overflow policy"),
+                ))
+            }
+        };
+        simple::add_numbers(a, b, policy)
+    })
+}
+
+/// Description of the last error on this thread, or NULL if the last call succeeded
+///
+/// Release the result with `demo_rust_native_plugin_free_string`.
+#[no_mangle]
+pub extern "C" fn demo_rust_native_plugin_last_error_message() -> *mut c_char {
+    LAST_ERROR.with_borrow(|error| {
+        error
+            .clone()
+            .map_or(std::ptr::null_mut(), CString::into_raw)
+    })
+}
+
+/// Release a string returned by this library
+///
+/// # Safety
+///
+/// `s` must be NULL or a pointer returned by this library that has not been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn demo_rust_native_plugin_free_string(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Run `f`, store its result in `*out` and record the outcome for `last_error_message`
+unsafe fn call<T>(out: *mut T, f: impl FnOnce() -> Result<T, PluginError>) -> Status {
+    let result = if out.is_null() {
+        Err(PluginError::invalid_argument("out", "must not be NULL"))
+    } else {
+        panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+            let details = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "panic".to_owned());
+            Err(PluginError::Internal { details })
+        })
+    };
+    let (status, error) = match result {
+        Ok(value) => {
+            out.write(value);
+            (Status::Ok, None)
+        }
+        Err(error) => (
+            Status::from(&error),
+            // Display output never contains NUL unless an argument did, and
+            // string arguments are NUL-terminated.
+            CString::new(error.to_string()).ok(),
+        ),
+    };
+    LAST_ERROR.set(error);
+    status
+}
+
+/// Borrow a C string argument as UTF-8
+unsafe fn borrow_str<'a>(s: *const c_char, name: &str) -> Result<&'a str, PluginError> {
+    if s.is_null() {
+        return Err(PluginError::invalid_argument(name, "must not be NULL"));
+    }
+    CStr::from_ptr(s)
+        .to_str()
+        .map_err(|e| PluginError::invalid_argument(name, e.to_string()))
+}
@@ -0,0 +1,61 @@
+//! Exact Fibonacci numbers computed with the fast doubling identities.
+
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// Largest `n` accepted for exact results; `F(n)` there already has about two million digits.
+pub(crate) const MAX_BIG_N: u32 = 10_000_000;
+
+/// Returns `F(n)`, reduced by `modulus` when one is given.
+///
+/// `on_step(done, total)` runs before each doubling step; returning `false`
+/// abandons the computation and yields `None`.
+pub(crate) fn fibonacci(
+    n: u32,
+    modulus: Option<&BigUint>,
+    on_step: &mut dyn FnMut(u32, u32) -> bool,
+) -> Option<BigUint> {
+    fibonacci_pair(n, modulus, on_step).map(|(a, _)| a)
+}
+
+/// Returns `(F(n), F(n + 1))`, reduced by `modulus` when one is given.
+///
+/// Walks the bits of `n` from the top using
+/// `F(2k) = F(k) * (2F(k+1) - F(k))` and `F(2k+1) = F(k)^2 + F(k+1)^2`,
+/// so only `O(log n)` big-integer multiplications are needed.
+pub(crate) fn fibonacci_pair(
+    n: u32,
+    modulus: Option<&BigUint>,
+    on_step: &mut dyn FnMut(u32, u32) -> bool,
+) -> Option<(BigUint, BigUint)> {
+    let reduce = |x: BigUint| match modulus {
+        Some(m) => x % m,
+        None => x,
+    };
+
+    // Invariant: a = F(k), b = F(k + 1) for the prefix k of n processed so far.
+    let mut a = BigUint::zero();
+    let mut b = BigUint::one();
+    let steps = u32::BITS - n.leading_zeros();
+    for bit in (0..steps).rev() {
+        if !on_step(steps - 1 - bit, steps) {
+            return None;
+        }
+        // Once reduced, F(k + 1) may be smaller than F(k), so stay non-negative
+        // by adding the modulus before subtracting.
+        let twice_b_minus_a = match modulus {
+            Some(m) => (&b << 1) + m - &a,
+            None => (&b << 1) - &a,
+        };
+        let even = reduce(&a * twice_b_minus_a);
+        let odd = reduce(&a * &a + &b * &b);
+        if (n >> bit) & 1 == 0 {
+            a = even;
+            b = odd;
+        } else {
+            b = reduce(&even + &odd);
+            a = odd;
+        }
+    }
+    Some((a, b))
+}
@@ -0,0 +1,38 @@
+//! C entry points that describe the API this library implements.
+//!
+//! They bypass the generated dispatchers, so bindings generated for a different
+//! API can still call them and report a mismatch instead of sending func IDs
+//! the dispatchers do not know.
+
+use std::ffi::{c_char, CString};
+
+use serde_json::json;
+
+use crate::api::info::build_info;
+
+/// `(func_id, path, signature, sync)` for every function the generated dispatchers accept
+const API_FUNCTIONS: &[(i32, &str, &str, bool)] =
+    include!(concat!(env!("OUT_DIR"), "/api_surface.rs"));
+
+/// JSON description of the API and build; release it with `demo_rust_native_plugin_free_string`
+#[no_mangle]
+pub extern "C" fn demo_rust_native_plugin_api_surface() -> *mut c_char {
+    let info = build_info();
+    let functions: Vec<_> = API_FUNCTIONS
+        .iter()
+        .map(|&(func_id, path, signature, sync)| {
+            json!({ "func_id": func_id, "path": path, "signature": signature, "sync": sync })
+        })
+        .collect();
+    let surface = json!({
+        "content_hash": info.frb_content_hash,
+        "codegen_version": info.frb_codegen_version,
+        "crate_version": info.crate_version,
+        "git_commit": info.git_commit,
+        "target": info.target,
+        "profile": info.profile,
+        "functions": functions,
+    });
+    // JSON escapes control characters, so the text never contains a NUL byte.
+    CString::new(surface.to_string()).unwrap().into_raw()
+}
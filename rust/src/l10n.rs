@@ -0,0 +1,176 @@
+//! Fluent message bundles and locale negotiation.
+//!
+//! Each locale has one bundle holding the resources bundled with the crate and any
+//! added at runtime; later resources override earlier messages with the same id.
+//! Adding a resource builds a new bundle and swaps it in, so the lock is only ever
+//! held to look bundles up or replace one.
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use fluent_langneg::{negotiate_languages, NegotiationStrategy};
+use unic_langid::{CharacterDirection, LanguageIdentifier};
+
+/// Locale used when nothing better matches; it has every message
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// FTL resources compiled into the crate, by locale
+const BUNDLED: &[(&str, &str)] = &[
+    ("ar", include_str!("../locales/ar/greet.ftl")),
+    ("de", include_str!("../locales/de/greet.ftl")),
+    ("en", include_str!("../locales/en/greet.ftl")),
+    ("es", include_str!("../locales/es/greet.ftl")),
+    ("fr", include_str!("../locales/fr/greet.ftl")),
+    ("he", include_str!("../locales/he/greet.ftl")),
+    ("hi", include_str!("../locales/hi/greet.ftl")),
+    ("it", include_str!("../locales/it/greet.ftl")),
+    ("ja", include_str!("../locales/ja/greet.ftl")),
+    ("ko", include_str!("../locales/ko/greet.ftl")),
+    ("pt", include_str!("../locales/pt/greet.ftl")),
+    ("pt-BR", include_str!("../locales/pt-BR/greet.ftl")),
+    ("ru", include_str!("../locales/ru/greet.ftl")),
+    ("tr", include_str!("../locales/tr/greet.ftl")),
+    ("zh", include_str!("../locales/zh/greet.ftl")),
+];
+
+type Bundle = FluentBundle<Arc<FluentResource>>;
+
+/// The resources of a locale, in the order they were added, and the bundle built from them
+struct Translations {
+    resources: Vec<Arc<FluentResource>>,
+    bundle: Bundle,
+}
+
+impl Translations {
+    fn new(locale: LanguageIdentifier, resources: Vec<Arc<FluentResource>>) -> Self {
+        let mut bundle = FluentBundle::new_concurrent(vec![locale]);
+        // Arguments are wrapped with `isolate` instead, only where directions can mix
+        bundle.set_use_isolating(false);
+        for resource in &resources {
+            bundle.add_resource_overriding(Arc::clone(resource));
+        }
+        Self { resources, bundle }
+    }
+}
+
+static BUNDLES: LazyLock<RwLock<HashMap<LanguageIdentifier, Arc<Translations>>>> =
+    LazyLock::new(|| {
+        let bundles = BUNDLED
+            .iter()
+            .map(|(locale, source)| {
+                let locale: LanguageIdentifier = locale.parse().unwrap();
+                let resource = FluentResource::try_new((*source).to_owned())
+                    .unwrap_or_else(|(_, errors)| panic!("locales/{locale}/greet.ftl: {errors:?}"));
+                let translations = Translations::new(locale.clone(), vec![Arc::new(resource)]);
+                (locale, Arc::new(translations))
+            })
+            .collect();
+        RwLock::new(bundles)
+    });
+
+/// Held while adding a resource, so that concurrent additions to a locale all take effect
+static ADDING: Mutex<()> = Mutex::new(());
+
+/// Every locale with messages, sorted
+pub fn locales() -> Vec<LanguageIdentifier> {
+    let mut locales: Vec<_> = BUNDLES.read().unwrap().keys().cloned().collect();
+    locales.sort_by_key(|locale| locale.to_string());
+    locales
+}
+
+/// Locales to look messages up in for `requested`, best first and ending with the default
+///
+/// `pt-BR` gives `pt-BR`, `pt`, `en`. Locales that do not parse get just the default.
+pub fn fallback_chain(requested: &str) -> Vec<LanguageIdentifier> {
+    let default: LanguageIdentifier = DEFAULT_LOCALE.parse().unwrap();
+    let requested: Vec<LanguageIdentifier> = match requested.parse() {
+        Ok(locale) => vec![locale],
+        Err(e) => {
+            log::debug!("ignoring locale `{requested}`: {e}");
+            Vec::new()
+        }
+    };
+    let available = locales();
+    negotiate_languages(
+        &requested,
+        &available,
+        Some(&default),
+        NegotiationStrategy::Filtering,
+    )
+    .into_iter()
+    .cloned()
+    .collect()
+}
+
+/// Add the messages in FTL `source` to `locale`, replacing any with the same id
+///
+/// Fails with a description of the first syntax error, leaving the messages unchanged.
+pub fn add_resource(locale: LanguageIdentifier, source: String) -> Result<(), String> {
+    let resource = FluentResource::try_new(source).map_err(|(_, errors)| {
+        let error = &errors[0];
+        format!("{error} at byte {}", error.pos.start)
+    })?;
+    let _adding = ADDING.lock().unwrap();
+    let mut resources = BUNDLES
+        .read()
+        .unwrap()
+        .get(&locale)
+        .map(|translations| translations.resources.clone())
+        .unwrap_or_default();
+    resources.push(Arc::new(resource));
+    let translations = Arc::new(Translations::new(locale.clone(), resources));
+    BUNDLES.write().unwrap().insert(locale, translations);
+    Ok(())
+}
+
+/// Format message `id` from the first locale in `chain` that has it
+pub fn format(chain: &[LanguageIdentifier], id: &str, args: &FluentArgs) -> Option<String> {
+    let found: Vec<_> = {
+        let bundles = BUNDLES.read().unwrap();
+        chain
+            .iter()
+            .filter_map(|locale| Some((locale, Arc::clone(bundles.get(locale)?))))
+            .collect()
+    };
+    for (locale, translations) in found {
+        let bundle = &translations.bundle;
+        let Some(pattern) = bundle.get_message(id).and_then(|message| message.value()) else {
+            continue;
+        };
+        let mut errors = Vec::new();
+        let text = bundle.format_pattern(pattern, Some(args), &mut errors);
+        if errors.is_empty() {
+            return Some(text.into_owned());
+        }
+        log::warn!("formatting `{id}` in {locale} failed: {errors:?}");
+    }
+    None
+}
+
+pub fn is_rtl(locale: &LanguageIdentifier) -> bool {
+    locale.character_direction() == CharacterDirection::RTL
+}
+
+/// Wrap `text` in Unicode directional isolates if it goes into a right-to-left message
+/// or has right-to-left characters, so that it cannot reorder the text around it
+pub fn isolate(text: &str, rtl_message: bool) -> String {
+    if rtl_message || text.chars().any(is_rtl_char) {
+        format!("\u{2068}{text}\u{2069}")
+    } else {
+        text.to_owned()
+    }
+}
+
+/// Whether `c` is in a block of a right-to-left script, e.g. Hebrew, Arabic or Syriac
+fn is_rtl_char(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0590}'..='\u{08FF}'
+            | '\u{FB1D}'..='\u{FDFF}'
+            | '\u{FE70}'..='\u{FEFF}'
+            | '\u{10800}'..='\u{10FFF}'
+            | '\u{1E800}'..='\u{1EFFF}'
+    )
+}
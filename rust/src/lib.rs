@@ -1,2 +1,14 @@
 pub mod api;
+#[cfg(feature = "cli")]
+pub mod daemon;
+mod executor;
+mod ffi;
+mod fibonacci;
 mod frb_generated;
+mod handshake;
+mod l10n;
+mod logger;
+mod names;
+pub mod registry;
+mod templating;
+mod zones;
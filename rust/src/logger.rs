@@ -0,0 +1,189 @@
+use std::cell::Cell;
+use std::io::Write;
+use std::sync::{Mutex, RwLock};
+
+use log::kv::{Error, Key, Value, VisitSource};
+use log::{LevelFilter, Log, Metadata, Record};
+use web_time::{SystemTime, UNIX_EPOCH};
+
+use crate::api::logging::{LogField, LogRecord};
+use crate::frb_generated::StreamSink;
+
+static LOGGER: PluginLogger = PluginLogger;
+static FILTER: RwLock<Filter> = RwLock::new(Filter {
+    level: LevelFilter::Info,
+    targets: Vec::new(),
+});
+/// Subscribers with the ids that tell them apart once copied out of the lock
+static SINKS: Mutex<Sinks> = Mutex::new(Sinks {
+    next_id: 0,
+    sinks: Vec::new(),
+});
+
+thread_local! {
+    /// Whether this thread is sending a record to the sinks, so that a record logged
+    /// meanwhile, e.g. by the panic hook, does not send again
+    static SENDING: Cell<bool> = const { Cell::new(false) };
+}
+
+struct Sinks {
+    next_id: u64,
+    sinks: Vec<(u64, StreamSink<LogRecord>)>,
+}
+
+struct PluginLogger;
+
+impl Log for PluginLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= FILTER.read().unwrap().level_for(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let mut fields = FieldCollector(Vec::new());
+        let _ = record.key_values().visit(&mut fields);
+        let fields = fields.0;
+
+        let mut line = format!("[{} {}] {}", record.level(), record.target(), record.args());
+        for field in &fields {
+            line.push_str(&format!(" {}={}", field.key, field.value));
+        }
+        write_line(&line);
+
+        if SENDING.replace(true) {
+            return;
+        }
+        let _sending = SendingGuard;
+        // Sending runs Dart port code, so it happens outside the lock.
+        let sinks = SINKS.lock().unwrap().sinks.clone();
+        if sinks.is_empty() {
+            return;
+        }
+        let entry = LogRecord {
+            level: record.level().into(),
+            target: record.target().to_owned(),
+            message: record.args().to_string(),
+            timestamp_millis: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_millis() as i64),
+            fields,
+        };
+        // A failed send means the Dart side cancelled its subscription
+        let closed: Vec<u64> = sinks
+            .iter()
+            .filter(|(_, sink)| sink.add(entry.clone()).is_err())
+            .map(|(id, _)| *id)
+            .collect();
+        if !closed.is_empty() {
+            SINKS
+                .lock()
+                .unwrap()
+                .sinks
+                .retain(|(id, _)| !closed.contains(id));
+        }
+    }
+
+    fn flush(&self) {
+        let _ = std::io::stderr().flush();
+    }
+}
+
+/// Clears `SENDING` when sending ends, even by a panic
+struct SendingGuard;
+
+impl Drop for SendingGuard {
+    fn drop(&mut self) {
+        SENDING.set(false);
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn write_line(line: &str) {
+    let _ = writeln!(std::io::stderr().lock(), "{line}");
+}
+
+/// Stderr goes nowhere on wasm32-unknown-unknown, so use the browser or Node console
+#[cfg(target_family = "wasm")]
+fn write_line(line: &str) {
+    web_sys::console::log_1(&line.into());
+}
+
+struct Filter {
+    level: LevelFilter,
+    /// Per-target overrides; the longest matching prefix wins
+    targets: Vec<(String, LevelFilter)>,
+}
+
+impl Filter {
+    fn level_for(&self, target: &str) -> LevelFilter {
+        self.targets
+            .iter()
+            .filter(|(prefix, _)| target_matches(target, prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.level, |(_, level)| *level)
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.targets
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.level, Ord::max)
+    }
+}
+
+fn target_matches(target: &str, prefix: &str) -> bool {
+    target
+        .strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+}
+
+struct FieldCollector(Vec<LogField>);
+
+impl<'kvs> VisitSource<'kvs> for FieldCollector {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), Error> {
+        self.0.push(LogField {
+            key: key.to_string(),
+            value: value.to_string(),
+        });
+        Ok(())
+    }
+}
+
+fn update_filter(f: impl FnOnce(&mut Filter)) {
+    let mut filter = FILTER.write().unwrap();
+    f(&mut filter);
+    log::set_max_level(filter.max_level());
+}
+
+/// Install the crate logger; a logger installed earlier by the host is left in place
+///
+/// Levels set before installing are kept; the default is `Info`.
+pub(crate) fn install() {
+    if log::set_logger(&LOGGER).is_ok() {
+        update_filter(|_| {});
+    }
+}
+
+pub(crate) fn set_level(level: LevelFilter) {
+    update_filter(|filter| filter.level = level);
+}
+
+pub(crate) fn set_target_level(target: String, level: LevelFilter) {
+    update_filter(|filter| {
+        filter.targets.retain(|(prefix, _)| *prefix != target);
+        filter.targets.push((target, level));
+    });
+}
+
+pub(crate) fn clear_target_levels() {
+    update_filter(|filter| filter.targets.clear());
+}
+
+pub(crate) fn add_sink(sink: StreamSink<LogRecord>) {
+    let mut sinks = SINKS.lock().unwrap();
+    let id = sinks.next_id;
+    sinks.next_id += 1;
+    sinks.sinks.push((id, sink));
+}
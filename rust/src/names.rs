@@ -0,0 +1,112 @@
+//! Normalization and spoofing checks for names shown to other users.
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_properties::{GeneralCategory, UnicodeGeneralCategory};
+use unicode_script::{Script, UnicodeScript};
+use unicode_security::MixedScript;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::api::simple::NameWarning;
+
+/// Longest name `greet` accepts, in user-perceived characters
+pub const DEFAULT_MAX_GRAPHEMES: u32 = 64;
+
+const ZWNJ: char = '\u{200C}';
+const ZWJ: char = '\u{200D}';
+
+/// A name after `clean`
+pub struct Cleaned {
+    pub name: String,
+    pub graphemes: u32,
+    /// Scripts of the letters, in order of first use
+    pub scripts: Vec<String>,
+    pub warnings: Vec<NameWarning>,
+}
+
+/// Drop characters that are invisible or change the direction of surrounding text,
+/// collapse whitespace to single spaces and NFC-normalize what is left
+///
+/// Normalization comes last because removing a character can leave a combining mark
+/// next to a letter it should compose with.
+pub fn clean(raw: &str) -> Cleaned {
+    let mut warnings = Vec::new();
+    let mut removed = Vec::new();
+    let visible: String = raw
+        .chars()
+        .filter_map(|c| match c {
+            c if c.is_whitespace() => Some(' '),
+            c if is_hidden(c) => {
+                removed.push(u32::from(c));
+                None
+            }
+            c => Some(c),
+        })
+        .collect();
+
+    // Joiners only mean something between two characters of a word
+    let mut words = Vec::new();
+    for word in visible.split(' ') {
+        let start = word.len() - word.trim_start_matches([ZWJ, ZWNJ]).len();
+        let trimmed = word[start..].trim_end_matches([ZWJ, ZWNJ]);
+        let end = start + trimmed.len();
+        removed.extend(
+            word[..start]
+                .chars()
+                .chain(word[end..].chars())
+                .map(u32::from),
+        );
+        if !trimmed.is_empty() {
+            words.push(trimmed);
+        }
+    }
+    let joined = words.join(" ");
+    let name: String = joined.nfc().collect();
+    if name != joined {
+        warnings.push(NameWarning::Normalized);
+    }
+    if !removed.is_empty() {
+        warnings.push(NameWarning::RemovedCharacters {
+            code_points: removed,
+        });
+    }
+
+    for word in name.split(' ') {
+        if !word.is_single_script() {
+            warnings.push(NameWarning::MixedScript {
+                word: word.to_owned(),
+                scripts: scripts(word),
+            });
+        }
+    }
+    Cleaned {
+        graphemes: name.graphemes(true).count() as u32,
+        scripts: scripts(&name),
+        name,
+        warnings,
+    }
+}
+
+/// Control and format characters other than joiners, which include the bidirectional
+/// overrides and isolates, and the blank fillers that render as nothing
+fn is_hidden(c: char) -> bool {
+    match c.general_category() {
+        GeneralCategory::Control => true,
+        GeneralCategory::Format => c != ZWJ && c != ZWNJ,
+        _ => matches!(
+            c,
+            '\u{115F}' | '\u{1160}' | '\u{2800}' | '\u{3164}' | '\u{FFA0}'
+        ),
+    }
+}
+
+fn scripts(text: &str) -> Vec<String> {
+    let mut scripts: Vec<Script> = Vec::new();
+    for script in text.chars().map(|c| c.script()) {
+        if !matches!(script, Script::Common | Script::Inherited | Script::Unknown)
+            && !scripts.contains(&script)
+        {
+            scripts.push(script);
+        }
+    }
+    scripts.iter().map(|s| s.full_name().to_owned()).collect()
+}
@@ -0,0 +1,608 @@
+//! `crate::api` functions callable by name with JSON arguments.
+//!
+//! Functions that take or return handles (`CancellationToken`, `ProgressReporter`,
+//! `NativeBuffer`, `PluginError` methods) or Dart streams other than results are
+//! left out, as are the `_sync` variants, which compute the same results, and
+//! `dispatch`, which calls into this table. `tests/dispatch.rs` checks the table
+//! against the API schema and lists every function left out.
+
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::api::cancellation::CancellationToken;
+use crate::api::error::PluginError;
+use crate::api::progress::ProgressReporter;
+use crate::api::{
+    batch, buffer, info, init, locale, logging, simple, style, templates, time, workers,
+};
+
+/// Type of a parameter or result as seen by JSON callers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ty {
+    Unit,
+    Bool,
+    /// An integer; the name is the Rust type, e.g. `u32`
+    Int(&'static str),
+    Float,
+    String,
+    /// A list of byte values
+    Bytes,
+    /// One of the listed snake_case names
+    Enum(&'static str, &'static [&'static str]),
+    /// A JSON object matching the named `crate::api` struct
+    Struct(&'static str),
+    List(&'static Ty),
+    /// May be omitted or `null`
+    Optional(&'static Ty),
+}
+
+/// A named parameter of a registered function
+#[derive(Debug, Clone, Copy)]
+pub struct Param {
+    pub name: &'static str,
+    pub ty: Ty,
+}
+
+/// Where a running function sends progress and stream items
+pub struct Context<'a> {
+    pub cancellation: &'a CancellationToken,
+    pub progress: &'a ProgressReporter,
+    /// Receives each stream item; returning `false` stops the stream
+    pub emit: &'a mut dyn FnMut(Value) -> bool,
+}
+
+/// A `crate::api` function callable with JSON arguments
+pub struct Function {
+    /// The Rust name, e.g. `calculate_fibonacci`
+    pub name: &'static str,
+    pub params: &'static [Param],
+    pub returns: Ty,
+    /// Type of the items passed to `Context::emit`, for streaming functions
+    pub stream: Option<Ty>,
+    call: fn(&Args, &mut Context) -> Result<Value, PluginError>,
+}
+
+impl Function {
+    /// Call the function with `args`, an object keyed by parameter name
+    pub fn call(
+        &self,
+        args: Map<String, Value>,
+        context: &mut Context,
+    ) -> Result<Value, PluginError> {
+        if let Some(unknown) = args
+            .keys()
+            .find(|key| !self.params.iter().any(|p| p.name == key.as_str()))
+        {
+            return Err(PluginError::invalid_argument(
+                unknown.as_str(),
+                format!("is not a parameter of {}", self.name),
+            ));
+        }
+        for param in self.params {
+            let present = args.get(param.name).is_some_and(|v| !v.is_null());
+            if !present && !matches!(param.ty, Ty::Optional(_)) {
+                return Err(PluginError::invalid_argument(param.name, "is required"));
+            }
+        }
+        (self.call)(&Args(args), context)
+    }
+}
+
+/// Arguments of one call
+struct Args(Map<String, Value>);
+
+impl Args {
+    fn get<T: DeserializeOwned>(&self, name: &str) -> Result<T, PluginError> {
+        let value = self.0.get(name).cloned().unwrap_or(Value::Null);
+        serde_json::from_value(value)
+            .map_err(|e| PluginError::invalid_argument(name, e.to_string()))
+    }
+}
+
+/// `error` as a JSON object with its `kind`, `code`, `message` and fields
+pub fn error_json(error: &PluginError) -> Value {
+    let mut value = json(error).unwrap_or_else(|_| Value::Object(Map::new()));
+    value["code"] = error.code().into();
+    value["message"] = error.message().into();
+    value
+}
+
+fn json(value: impl Serialize) -> Result<Value, PluginError> {
+    serde_json::to_value(value).map_err(|e| PluginError::Internal {
+        details: e.to_string(),
+    })
+}
+
+/// Look up a function by its Rust name
+pub fn find(name: &str) -> Option<&'static Function> {
+    FUNCTIONS.iter().find(|f| f.name == name)
+}
+
+/// Every registered function, in `crate::api` module order
+pub fn functions() -> &'static [Function] {
+    FUNCTIONS
+}
+
+const fn param(name: &'static str, ty: Ty) -> Param {
+    Param { name, ty }
+}
+
+const I64: Ty = Ty::Int("i64");
+const U32: Ty = Ty::Int("u32");
+const U64: Ty = Ty::Int("u64");
+const POLICY: Param = param(
+    "policy",
+    Ty::Enum("OverflowPolicy", &["checked", "saturating", "wrapping"]),
+);
+const BATCH_OP: Ty = Ty::Enum("BatchOp", &["add", "subtract", "multiply", "divide"]);
+const REDUCTION: Ty = Ty::Enum("Reduction", &["sum", "product", "min", "max"]);
+const LOG_LEVEL: Ty = Ty::Enum(
+    "LogLevel",
+    &["off", "error", "warn", "info", "debug", "trace"],
+);
+const GENDER: Ty = Ty::Enum("Gender", &["feminine", "masculine", "other"]);
+const I64_PAIR: &[Param] = &[param("a", Ty::List(&I64)), param("b", Ty::List(&I64))];
+const F64_PAIR: &[Param] = &[
+    param("a", Ty::List(&Ty::Float)),
+    param("b", Ty::List(&Ty::Float)),
+];
+const ARITHMETIC: &[Param] = &[param("a", I64), param("b", I64), POLICY];
+
+static FUNCTIONS: &[Function] = &[
+    // batch
+    Function {
+        name: "batch_i64",
+        params: &[I64_PAIR[0], I64_PAIR[1], param("op", BATCH_OP), POLICY],
+        returns: Ty::List(&I64),
+        stream: None,
+        call: |args, _| {
+            json(batch::batch_i64(
+                args.get("a")?,
+                args.get("b")?,
+                args.get("op")?,
+                args.get("policy")?,
+            )?)
+        },
+    },
+    Function {
+        name: "batch_f64",
+        params: &[F64_PAIR[0], F64_PAIR[1], param("op", BATCH_OP)],
+        returns: Ty::List(&Ty::Float),
+        stream: None,
+        call: |args, _| {
+            json(batch::batch_f64(
+                args.get("a")?,
+                args.get("b")?,
+                args.get("op")?,
+            )?)
+        },
+    },
+    Function {
+        name: "dot_product_i64",
+        params: &[I64_PAIR[0], I64_PAIR[1], POLICY],
+        returns: I64,
+        stream: None,
+        call: |args, _| {
+            json(batch::dot_product_i64(
+                args.get("a")?,
+                args.get("b")?,
+                args.get("policy")?,
+            )?)
+        },
+    },
+    Function {
+        name: "dot_product_f64",
+        params: F64_PAIR,
+        returns: Ty::Float,
+        stream: None,
+        call: |args, _| json(batch::dot_product_f64(args.get("a")?, args.get("b")?)?),
+    },
+    Function {
+        name: "prefix_sums_i64",
+        params: &[param("values", Ty::List(&I64)), POLICY],
+        returns: Ty::List(&I64),
+        stream: None,
+        call: |args, _| {
+            json(batch::prefix_sums_i64(
+                args.get("values")?,
+                args.get("policy")?,
+            )?)
+        },
+    },
+    Function {
+        name: "prefix_sums_f64",
+        params: &[param("values", Ty::List(&Ty::Float))],
+        returns: Ty::List(&Ty::Float),
+        stream: None,
+        call: |args, _| json(batch::prefix_sums_f64(args.get("values")?)),
+    },
+    Function {
+        name: "reduce_i64",
+        params: &[
+            param("values", Ty::List(&I64)),
+            param("reduction", REDUCTION),
+            POLICY,
+        ],
+        returns: I64,
+        stream: None,
+        call: |args, _| {
+            json(batch::reduce_i64(
+                args.get("values")?,
+                args.get("reduction")?,
+                args.get("policy")?,
+            )?)
+        },
+    },
+    Function {
+        name: "reduce_f64",
+        params: &[
+            param("values", Ty::List(&Ty::Float)),
+            param("reduction", REDUCTION),
+        ],
+        returns: Ty::Float,
+        stream: None,
+        call: |args, _| {
+            json(batch::reduce_f64(
+                args.get("values")?,
+                args.get("reduction")?,
+            )?)
+        },
+    },
+    // buffer
+    Function {
+        name: "checksum_bytes",
+        params: &[param("data", Ty::Bytes)],
+        returns: Ty::Int("u32"),
+        stream: None,
+        call: |args, _| json(buffer::checksum_bytes(args.get("data")?)),
+    },
+    Function {
+        name: "xor_bytes",
+        params: &[param("data", Ty::Bytes), param("key", Ty::Int("u8"))],
+        returns: Ty::Bytes,
+        stream: None,
+        call: |args, _| json(buffer::xor_bytes(args.get("data")?, args.get("key")?)),
+    },
+    // info
+    Function {
+        name: "build_info",
+        params: &[],
+        returns: Ty::Struct("BuildInfo"),
+        stream: None,
+        call: |_, _| json(info::build_info()),
+    },
+    Function {
+        name: "api_schema",
+        params: &[],
+        returns: Ty::String,
+        stream: None,
+        call: |_, _| json(info::api_schema()),
+    },
+    // init
+    Function {
+        name: "init_plugin",
+        params: &[param("config", Ty::Struct("PluginConfig"))],
+        returns: Ty::Unit,
+        stream: None,
+        call: |args, _| {
+            init::init_plugin(args.get("config")?)?;
+            Ok(Value::Null)
+        },
+    },
+    Function {
+        name: "plugin_config",
+        params: &[],
+        returns: Ty::Optional(&Ty::Struct("PluginConfig")),
+        stream: None,
+        call: |_, _| json(init::plugin_config()),
+    },
+    // locale
+    Function {
+        name: "negotiate_locale",
+        params: &[param("requested", Ty::String)],
+        returns: Ty::Struct("LocaleInfo"),
+        stream: None,
+        call: |args, _| json(locale::negotiate_locale(args.get("requested")?)),
+    },
+    Function {
+        name: "supported_locales",
+        params: &[],
+        returns: Ty::List(&Ty::String),
+        stream: None,
+        call: |_, _| json(locale::supported_locales()),
+    },
+    Function {
+        name: "add_translations",
+        params: &[param("locale", Ty::String), param("ftl", Ty::String)],
+        returns: Ty::Unit,
+        stream: None,
+        call: |args, _| {
+            locale::add_translations(args.get("locale")?, args.get("ftl")?)?;
+            Ok(Value::Null)
+        },
+    },
+    // logging
+    Function {
+        name: "set_log_level",
+        params: &[param("level", LOG_LEVEL)],
+        returns: Ty::Unit,
+        stream: None,
+        call: |args, _| {
+            logging::set_log_level(args.get("level")?);
+            Ok(Value::Null)
+        },
+    },
+    Function {
+        name: "set_log_target_level",
+        params: &[param("target", Ty::String), param("level", LOG_LEVEL)],
+        returns: Ty::Unit,
+        stream: None,
+        call: |args, _| {
+            logging::set_log_target_level(args.get("target")?, args.get("level")?);
+            Ok(Value::Null)
+        },
+    },
+    Function {
+        name: "clear_log_target_levels",
+        params: &[],
+        returns: Ty::Unit,
+        stream: None,
+        call: |_, _| {
+            logging::clear_log_target_levels();
+            Ok(Value::Null)
+        },
+    },
+    // simple
+    Function {
+        name: "greet",
+        params: &[
+            param("name", Ty::String),
+            param("locale", Ty::Optional(&Ty::String)),
+            param("gender", Ty::Optional(&GENDER)),
+            param("template", Ty::Optional(&Ty::String)),
+            param("style", Ty::Optional(&Ty::Struct("GreetingStyle"))),
+            param("time_zone", Ty::Optional(&Ty::String)),
+            param("now_utc_millis", Ty::Optional(&I64)),
+        ],
+        returns: Ty::String,
+        stream: None,
+        call: |args, _| {
+            json(simple::greet(
+                args.get("name")?,
+                args.get("locale")?,
+                args.get("gender")?,
+                args.get("template")?,
+                args.get("style")?,
+                args.get("time_zone")?,
+                args.get("now_utc_millis")?,
+            )?)
+        },
+    },
+    Function {
+        name: "process_name",
+        params: &[
+            param("name", Ty::String),
+            param("max_graphemes", Ty::Optional(&U32)),
+        ],
+        returns: Ty::Struct("ProcessedName"),
+        stream: None,
+        call: |args, _| {
+            json(simple::process_name(
+                args.get("name")?,
+                args.get("max_graphemes")?,
+            )?)
+        },
+    },
+    Function {
+        name: "calculate_fibonacci",
+        params: &[param("n", U32)],
+        returns: U64,
+        stream: None,
+        call: |args, _| json(simple::calculate_fibonacci(args.get("n")?)?),
+    },
+    Function {
+        name: "calculate_fibonacci_big",
+        params: &[param("n", U32), param("modulus", Ty::Optional(&U64))],
+        returns: Ty::String,
+        stream: None,
+        call: |args, context| {
+            json(simple::calculate_fibonacci_big(
+                args.get("n")?,
+                args.get("modulus")?,
+                context.cancellation,
+                context.progress,
+            )?)
+        },
+    },
+    Function {
+        name: "fibonacci_sequence",
+        params: &[
+            param("start", U32),
+            param("count", U32),
+            param("chunk_size", U32),
+        ],
+        returns: Ty::Unit,
+        stream: Some(Ty::Struct("FibonacciChunk")),
+        call: |args, context| {
+            let emit = &mut *context.emit;
+            simple::fibonacci_chunks(
+                args.get("start")?,
+                args.get("count")?,
+                args.get("chunk_size")?,
+                context.cancellation,
+                &mut |chunk| serde_json::to_value(chunk).is_ok_and(&mut *emit),
+            )?;
+            Ok(Value::Null)
+        },
+    },
+    Function {
+        name: "add_numbers",
+        params: ARITHMETIC,
+        returns: I64,
+        stream: None,
+        call: |args, _| {
+            json(simple::add_numbers(
+                args.get("a")?,
+                args.get("b")?,
+                args.get("policy")?,
+            )?)
+        },
+    },
+    Function {
+        name: "subtract_numbers",
+        params: ARITHMETIC,
+        returns: I64,
+        stream: None,
+        call: |args, _| {
+            json(simple::subtract_numbers(
+                args.get("a")?,
+                args.get("b")?,
+                args.get("policy")?,
+            )?)
+        },
+    },
+    Function {
+        name: "multiply_numbers",
+        params: ARITHMETIC,
+        returns: I64,
+        stream: None,
+        call: |args, _| {
+            json(simple::multiply_numbers(
+                args.get("a")?,
+                args.get("b")?,
+                args.get("policy")?,
+            )?)
+        },
+    },
+    Function {
+        name: "divide_numbers",
+        params: ARITHMETIC,
+        returns: I64,
+        stream: None,
+        call: |args, _| {
+            json(simple::divide_numbers(
+                args.get("a")?,
+                args.get("b")?,
+                args.get("policy")?,
+            )?)
+        },
+    },
+    Function {
+        name: "pow_number",
+        params: &[param("base", I64), param("exponent", U32), POLICY],
+        returns: I64,
+        stream: None,
+        call: |args, _| {
+            json(simple::pow_number(
+                args.get("base")?,
+                args.get("exponent")?,
+                args.get("policy")?,
+            )?)
+        },
+    },
+    // style
+    Function {
+        name: "emoji_support",
+        params: &[
+            param("emoji", Ty::String),
+            param("supported_version", Ty::Optional(&Ty::String)),
+        ],
+        returns: Ty::Struct("EmojiSupport"),
+        stream: None,
+        call: |args, _| {
+            json(style::emoji_support(
+                args.get("emoji")?,
+                args.get("supported_version")?,
+            )?)
+        },
+    },
+    // templates
+    Function {
+        name: "register_greeting_template",
+        params: &[param("name", Ty::String), param("source", Ty::String)],
+        returns: Ty::Unit,
+        stream: None,
+        call: |args, _| {
+            templates::register_greeting_template(args.get("name")?, args.get("source")?)?;
+            Ok(Value::Null)
+        },
+    },
+    Function {
+        name: "unregister_greeting_template",
+        params: &[param("name", Ty::String)],
+        returns: Ty::Bool,
+        stream: None,
+        call: |args, _| json(templates::unregister_greeting_template(args.get("name")?)),
+    },
+    Function {
+        name: "greeting_templates",
+        params: &[],
+        returns: Ty::List(&Ty::String),
+        stream: None,
+        call: |_, _| json(templates::greeting_templates()),
+    },
+    // time
+    Function {
+        name: "time_of_day",
+        params: &[
+            param("time_zone", Ty::String),
+            param("now_utc_millis", Ty::Optional(&I64)),
+        ],
+        returns: Ty::Enum("TimeOfDay", &["morning", "afternoon", "evening", "night"]),
+        stream: None,
+        call: |args, _| {
+            json(time::time_of_day(
+                args.get("time_zone")?,
+                args.get("now_utc_millis")?,
+            )?)
+        },
+    },
+    // workers
+    Function {
+        name: "set_task_route",
+        params: &[
+            param("function", Ty::String),
+            param("pool", Ty::Enum("WorkerPoolKind", &["cpu", "blocking"])),
+            param(
+                "priority",
+                Ty::Enum("TaskPriority", &["background", "normal", "interactive"]),
+            ),
+        ],
+        returns: Ty::Unit,
+        stream: None,
+        call: |args, _| {
+            workers::set_task_route(
+                args.get("function")?,
+                args.get("pool")?,
+                args.get("priority")?,
+            );
+            Ok(Value::Null)
+        },
+    },
+    Function {
+        name: "worker_pool_metrics",
+        params: &[],
+        returns: Ty::List(&Ty::Struct("WorkerPoolMetrics")),
+        stream: None,
+        call: |_, _| json(workers::worker_pool_metrics()),
+    },
+];
+
+impl fmt::Display for Ty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ty::Unit => write!(f, "()"),
+            Ty::Bool => write!(f, "bool"),
+            Ty::Int(name) => write!(f, "{name}"),
+            Ty::Float => write!(f, "f64"),
+            Ty::String => write!(f, "String"),
+            Ty::Bytes => write!(f, "Vec<u8>"),
+            Ty::Enum(name, _) | Ty::Struct(name) => write!(f, "{name}"),
+            Ty::List(item) => write!(f, "Vec<{item}>"),
+            Ty::Optional(inner) => write!(f, "Option<{inner}>"),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Greeting templates registered at runtime, rendered with MiniJinja.
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, RwLock};
+
+use minijinja::{AutoEscape, Environment, Error, UndefinedBehavior};
+use serde::Serialize;
+
+use crate::api::locale::Gender;
+use crate::api::time::TimeOfDay;
+
+/// Instructions one render may execute, so that loops cannot stall a call
+const FUEL: u64 = 10_000;
+
+/// Names of the `GreetingValues` fields
+const VARIABLES: [&str; 6] = [
+    "name",
+    "salutation",
+    "greeting",
+    "locale",
+    "gender",
+    "time_of_day",
+];
+
+/// Values a greeting template can use
+#[derive(Debug, Clone, Serialize)]
+pub struct GreetingValues {
+    pub name: String,
+    /// Localized word of greeting, e.g. `Hello`
+    pub salutation: String,
+    /// The localized greeting `greet` uses without a template, undecorated
+    pub greeting: String,
+    pub locale: String,
+    pub gender: &'static str,
+    /// `morning`, `afternoon`, `evening` or `night`; see `TimeOfDay`. `None` when `greet`
+    /// has no time zone, which it only allows for templates that do not use it
+    pub time_of_day: Option<&'static str>,
+}
+
+/// A registered template, compiled in an environment of its own
+pub struct Template {
+    name: String,
+    env: Environment<'static>,
+    uses_time_of_day: bool,
+}
+
+impl Template {
+    /// Whether the template reads `time_of_day`
+    pub fn uses_time_of_day(&self) -> bool {
+        self.uses_time_of_day
+    }
+
+    /// Render the template with `values`
+    pub fn render(&self, values: &GreetingValues) -> Result<String, String> {
+        let template = self.env.get_template(&self.name).unwrap();
+        template.render(values).map_err(describe)
+    }
+}
+
+/// Templates by name, so that registering compiles outside the lock and `get` only
+/// holds it to look a template up
+static TEMPLATES: LazyLock<RwLock<HashMap<String, Arc<Template>>>> = LazyLock::new(RwLock::default);
+
+fn environment() -> Environment<'static> {
+    let mut env = Environment::new();
+    // Greetings are plain text, whatever the template is called
+    env.set_auto_escape_callback(|_| AutoEscape::None);
+    env.set_undefined_behavior(UndefinedBehavior::Strict);
+    env.set_fuel(Some(FUEL));
+    env
+}
+
+/// Compile `source` as template `name`, replacing any template with that name
+///
+/// Variables other than the `GreetingValues` fields and MiniJinja's globals are
+/// rejected, and the template is rendered with example values for every gender and
+/// time of day, so unknown filters fail here rather than in `render` in any branch
+/// that only depends on those. Fails with a description of the error, leaving the
+/// templates unchanged.
+pub fn register(name: String, source: String) -> Result<(), String> {
+    let mut env = environment();
+    env.add_template_owned(name.clone(), source)
+        .map_err(describe)?;
+    let template = env.get_template(&name).map_err(describe)?;
+
+    // Nested names such as `name.len` start with the variable.
+    let variables: Vec<String> = template.undeclared_variables(true).into_iter().collect();
+    let root = |variable: &str| variable.split('.').next().unwrap_or_default().to_owned();
+    let uses_time_of_day = variables.iter().any(|v| root(v) == "time_of_day");
+    let mut unknown: Vec<String> = variables
+        .iter()
+        .filter(|v| {
+            let root = root(v);
+            !VARIABLES.contains(&root.as_str()) && env.globals().all(|(global, _)| global != root)
+        })
+        .cloned()
+        .collect();
+    if !unknown.is_empty() {
+        unknown.sort();
+        return Err(format!(
+            "unknown variables {}; templates can use {}",
+            unknown.join(", "),
+            VARIABLES.join(", ")
+        ));
+    }
+
+    for gender in [Gender::Feminine, Gender::Masculine, Gender::Other] {
+        for time_of_day in [
+            TimeOfDay::Morning,
+            TimeOfDay::Afternoon,
+            TimeOfDay::Evening,
+            TimeOfDay::Night,
+        ] {
+            let example = GreetingValues {
+                name: "Tom".to_owned(),
+                salutation: "Hello".to_owned(),
+                greeting: "Hello, Tom!".to_owned(),
+                locale: "en".to_owned(),
+                gender: gender.as_str(),
+                time_of_day: Some(time_of_day.as_str()),
+            };
+            template.render(&example).map_err(describe)?;
+        }
+    }
+    let template = Template {
+        name: name.clone(),
+        env,
+        uses_time_of_day,
+    };
+    TEMPLATES.write().unwrap().insert(name, Arc::new(template));
+    Ok(())
+}
+
+/// Remove template `name`; returns whether it existed
+pub fn unregister(name: &str) -> bool {
+    TEMPLATES.write().unwrap().remove(name).is_some()
+}
+
+/// Names of the registered templates, sorted
+pub fn names() -> Vec<String> {
+    let mut names: Vec<String> = TEMPLATES.read().unwrap().keys().cloned().collect();
+    names.sort();
+    names
+}
+
+/// Template `name`, or `None` if there is no such template
+pub fn get(name: &str) -> Option<Arc<Template>> {
+    TEMPLATES.read().unwrap().get(name).cloned()
+}
+
+/// `error` with its line number, if it has one
+fn describe(error: Error) -> String {
+    match error.line() {
+        Some(line) => match error.detail() {
+            Some(detail) => format!("line {line}: {detail}"),
+            None => format!("line {line}: {}", error.kind()),
+        },
+        None => error.to_string(),
+    }
+}
@@ -0,0 +1,24 @@
+//! Time zones and the hour of an instant in them.
+
+use chrono::{DateTime, Timelike, Utc};
+use chrono_tz::Tz;
+
+/// Look up an IANA time zone such as `Europe/Lisbon` in the embedded database
+pub fn parse(name: &str) -> Result<Tz, String> {
+    name.parse()
+        .map_err(|e: chrono_tz::ParseError| e.to_string())
+}
+
+/// The instant `millis` milliseconds after the Unix epoch, or the current one without it
+pub fn instant(millis: Option<i64>) -> Result<DateTime<Utc>, String> {
+    match millis {
+        Some(millis) => DateTime::from_timestamp_millis(millis)
+            .ok_or_else(|| format!("{millis} ms is outside the supported dates")),
+        None => Ok(Utc::now()),
+    }
+}
+
+/// The hour of `now` in `zone`
+pub fn hour(now: DateTime<Utc>, zone: Tz) -> u32 {
+    now.with_timezone(&zone).hour()
+}
@@ -0,0 +1,81 @@
+//! Overflow policies of the integer functions.
+
+use demo_rust_native_plugin::api::error::PluginError;
+use demo_rust_native_plugin::api::simple::{
+    add_numbers, divide_numbers, pow_number, OverflowPolicy,
+};
+
+fn is_overflow(result: Result<i64, PluginError>) -> bool {
+    matches!(result, Err(PluginError::Overflow { .. }))
+}
+
+#[test]
+fn dividing_the_minimum_by_minus_one_follows_the_policy() {
+    assert!(is_overflow(divide_numbers(
+        i64::MIN,
+        -1,
+        OverflowPolicy::Checked
+    )));
+    assert_eq!(
+        divide_numbers(i64::MIN, -1, OverflowPolicy::Saturating),
+        Ok(i64::MAX)
+    );
+    assert_eq!(
+        divide_numbers(i64::MIN, -1, OverflowPolicy::Wrapping),
+        Ok(i64::MIN)
+    );
+}
+
+#[test]
+fn division_by_zero_fails_under_every_policy() {
+    for policy in [
+        OverflowPolicy::Checked,
+        OverflowPolicy::Saturating,
+        OverflowPolicy::Wrapping,
+    ] {
+        assert!(matches!(
+            divide_numbers(1, 0, policy),
+            Err(PluginError::InvalidArgument { name, .. }) if name == "b"
+        ));
+    }
+}
+
+#[test]
+fn powers_follow_the_policy() {
+    assert!(is_overflow(pow_number(2, 63, OverflowPolicy::Checked)));
+    assert_eq!(pow_number(2, 63, OverflowPolicy::Saturating), Ok(i64::MAX));
+    assert_eq!(pow_number(2, 63, OverflowPolicy::Wrapping), Ok(i64::MIN));
+    // Odd powers of negative numbers saturate and wrap on the negative side.
+    assert!(is_overflow(pow_number(-3, 41, OverflowPolicy::Checked)));
+    assert_eq!(pow_number(-3, 41, OverflowPolicy::Saturating), Ok(i64::MIN));
+    assert_eq!(
+        pow_number(-3, 41, OverflowPolicy::Wrapping),
+        Ok((-3i64).wrapping_pow(41))
+    );
+    // Results that fit are the same under every policy.
+    for policy in [
+        OverflowPolicy::Checked,
+        OverflowPolicy::Saturating,
+        OverflowPolicy::Wrapping,
+    ] {
+        assert_eq!(pow_number(-2, 63, policy), Ok(i64::MIN));
+        assert_eq!(pow_number(7, 0, policy), Ok(1));
+    }
+}
+
+#[test]
+fn additions_follow_the_policy() {
+    assert!(is_overflow(add_numbers(
+        i64::MAX,
+        1,
+        OverflowPolicy::Checked
+    )));
+    assert_eq!(
+        add_numbers(i64::MIN, -1, OverflowPolicy::Saturating),
+        Ok(i64::MIN)
+    );
+    assert_eq!(
+        add_numbers(i64::MAX, 1, OverflowPolicy::Wrapping),
+        Ok(i64::MIN)
+    );
+}
@@ -0,0 +1,74 @@
+//! Batch arithmetic on both sides of the parallel threshold.
+
+#![cfg(not(target_family = "wasm"))]
+
+use demo_rust_native_plugin::api::batch::{
+    dot_product_i64, prefix_sums_i64, reduce_i64, Reduction,
+};
+use demo_rust_native_plugin::api::error::PluginError;
+use demo_rust_native_plugin::api::simple::OverflowPolicy;
+
+/// Long enough for the parallel path
+const PARALLEL_LEN: usize = 16 * 1024;
+
+/// `a` and `b` padded with zeros to `len`
+fn padded(a: &[i64], b: &[i64], len: usize) -> (Vec<i64>, Vec<i64>) {
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    a.resize(len, 0);
+    b.resize(len, 0);
+    (a, b)
+}
+
+#[test]
+fn dot_product_is_exact_when_partial_sums_leave_i128() {
+    // Two products of 2^126, two of about -2^126 and a small remainder: exactly zero.
+    let a = [i64::MIN; 6];
+    let b = [i64::MIN, i64::MIN, i64::MAX, i64::MAX, 1, 1];
+    for len in [a.len(), PARALLEL_LEN] {
+        for policy in [OverflowPolicy::Checked, OverflowPolicy::Saturating] {
+            let (a, b) = padded(&a, &b, len);
+            assert_eq!(dot_product_i64(a, b, policy), Ok(0), "{len} {policy:?}");
+        }
+    }
+}
+
+#[test]
+fn dot_product_overflow_follows_the_policy() {
+    let a = [i64::MAX; 3];
+    let b = [i64::MAX; 3];
+    for len in [a.len(), PARALLEL_LEN] {
+        let (a, b) = padded(&a, &b, len);
+        assert!(matches!(
+            dot_product_i64(a.clone(), b.clone(), OverflowPolicy::Checked),
+            Err(PluginError::Overflow { .. })
+        ));
+        assert_eq!(
+            dot_product_i64(a.clone(), b.clone(), OverflowPolicy::Saturating),
+            Ok(i64::MAX)
+        );
+        // 3 * (2^63 - 1)^2 = 3 * 2^126 - 3 * 2^64 + 3, which is 3 modulo 2^64
+        assert_eq!(dot_product_i64(a, b, OverflowPolicy::Wrapping), Ok(3));
+    }
+}
+
+#[test]
+fn parallel_results_match_serial_ones() {
+    let values: Vec<i64> = (0..PARALLEL_LEN as i64 * 4)
+        .map(|i| i * 7 - 1_000)
+        .collect();
+    let serial: i64 = values.iter().sum();
+    assert_eq!(
+        reduce_i64(values.clone(), Reduction::Sum, OverflowPolicy::Checked),
+        Ok(serial)
+    );
+    let sums = prefix_sums_i64(values.clone(), OverflowPolicy::Checked).unwrap();
+    assert_eq!(sums.last(), Some(&serial));
+    assert_eq!(
+        sums[SCAN_BOUNDARY],
+        values[..=SCAN_BOUNDARY].iter().sum::<i64>()
+    );
+}
+
+/// First element of the second parallel scan block
+const SCAN_BOUNDARY: usize = 4 * 1024;
@@ -0,0 +1,19 @@
+//! Native buffers.
+
+use demo_rust_native_plugin::api::buffer::{checksum_buffer, checksum_bytes, NativeBuffer};
+use demo_rust_native_plugin::api::error::PluginError;
+
+#[test]
+fn buffers_start_zeroed() {
+    let buffer = NativeBuffer::new(1000).unwrap();
+    assert_eq!(buffer.len(), 1000);
+    assert_eq!(checksum_buffer(&buffer), checksum_bytes(vec![0; 1000]));
+}
+
+#[test]
+fn lengths_that_cannot_be_allocated_fail() {
+    assert!(matches!(
+        NativeBuffer::new(usize::MAX),
+        Err(PluginError::InvalidArgument { name, .. }) if name == "len"
+    ));
+}
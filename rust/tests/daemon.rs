@@ -0,0 +1,160 @@
+//! JSON-RPC sessions with the daemon over in-memory buffers.
+
+use std::sync::{Arc, Mutex};
+
+use demo_rust_native_plugin::daemon::serve_connection;
+use serde_json::{json, Value};
+
+/// Messages the server writes for `input`, one request or batch per line
+fn serve(input: &str) -> Vec<Value> {
+    let output = Arc::new(Mutex::new(Vec::new()));
+    serve_connection(input.as_bytes(), output.clone()).unwrap();
+    let output = output.lock().unwrap();
+    output
+        .split(|b| *b == b'\n')
+        .filter(|line| !line.is_empty())
+        .map(|line| serde_json::from_slice(line).unwrap())
+        .collect()
+}
+
+/// The response to request `id` among `messages`
+fn response(messages: &[Value], id: i64) -> &Value {
+    messages
+        .iter()
+        .find(|m| m.get("id") == Some(&json!(id)))
+        .unwrap_or_else(|| panic!("no response to {id} in {messages:?}"))
+}
+
+#[test]
+fn answers_requests_with_named_or_positional_params() {
+    let messages = serve(concat!(
+        r#"{"jsonrpc":"2.0","id":1,"method":"greet","params":{"name":"Tom"}}"#,
+        "\n",
+        r#"{"jsonrpc":"2.0","id":2,"method":"add_numbers","params":[1,2,"checked"]}"#,
+        "\n",
+    ));
+    assert_eq!(messages.len(), 2);
+    assert_eq!(response(&messages, 1)["result"], "Hello, Tom! 🦀");
+    assert_eq!(response(&messages, 2)["result"], 3);
+}
+
+#[test]
+fn notifications_get_no_response() {
+    let messages = serve(concat!(
+        r#"{"jsonrpc":"2.0","method":"greet","params":{"name":"Tom"}}"#,
+        "\n",
+        r#"{"jsonrpc":"2.0","method":"no_such_method"}"#,
+        "\n",
+    ));
+    assert_eq!(messages, Vec::<Value>::new());
+}
+
+#[test]
+fn malformed_messages_are_reported() {
+    let messages = serve("{not json\n[]\n{\"jsonrpc\":\"1.0\",\"id\":3,\"method\":\"greet\"}\n");
+    assert_eq!(messages.len(), 3);
+    assert_eq!(messages[0]["id"], Value::Null);
+    assert_eq!(messages[0]["error"]["code"], -32700);
+    // Responses to the other two may arrive in either order.
+    let mut codes: Vec<_> = messages[1..]
+        .iter()
+        .map(|m| (m["id"].clone(), m["error"]["code"].clone()))
+        .collect();
+    codes.sort_by_key(|(id, _)| id.to_string());
+    assert_eq!(
+        codes,
+        [(json!(3), json!(-32600)), (Value::Null, json!(-32600))]
+    );
+}
+
+#[test]
+fn unknown_methods_and_bad_params_are_reported() {
+    let messages = serve(concat!(
+        r#"{"jsonrpc":"2.0","id":1,"method":"no_such_method"}"#,
+        "\n",
+        r#"{"jsonrpc":"2.0","id":2,"method":"greet","params":[1,2,3,4,5,6,7,8]}"#,
+        "\n",
+        r#"{"jsonrpc":"2.0","id":3,"method":"greet","params":{"name":7}}"#,
+        "\n",
+    ));
+    assert_eq!(response(&messages, 1)["error"]["code"], -32601);
+    assert_eq!(response(&messages, 2)["error"]["code"], -32602);
+    assert!(response(&messages, 3)["error"].is_object());
+}
+
+#[test]
+fn plugin_errors_carry_their_code_and_data() {
+    let messages =
+        serve(r#"{"jsonrpc":"2.0","id":1,"method":"calculate_fibonacci","params":{"n":94}}"#);
+    let error = &response(&messages, 1)["error"];
+    assert_eq!(error["code"], 1);
+    assert_eq!(error["data"]["kind"], "overflow");
+}
+
+#[test]
+fn batches_are_answered_together() {
+    let messages = serve(concat!(
+        "[",
+        r#"{"jsonrpc":"2.0","id":1,"method":"greet","params":{"name":"Tom"}},"#,
+        r#"{"jsonrpc":"2.0","id":2,"method":"add_numbers","params":[2,3,"checked"]},"#,
+        r#"{"jsonrpc":"2.0","method":"greet","params":{"name":"Ana"}},"#,
+        r#"{"jsonrpc":"2.0","id":3,"method":"no_such_method"}"#,
+        "]\n",
+    ));
+    let [Value::Array(responses)] = &messages[..] else {
+        panic!("expected one batch response, got {messages:?}");
+    };
+    assert_eq!(responses.len(), 3);
+    assert_eq!(response(responses, 1)["result"], "Hello, Tom! 🦀");
+    assert_eq!(response(responses, 2)["result"], 5);
+    assert_eq!(response(responses, 3)["error"]["code"], -32601);
+}
+
+#[test]
+fn stream_items_precede_the_response() {
+    let messages = serve(
+        r#"{"jsonrpc":"2.0","id":1,"method":"fibonacci_sequence","params":{"start":0,"count":10,"chunk_size":4}}"#,
+    );
+    let (last, items) = messages.split_last().unwrap();
+    assert_eq!(last["id"], 1);
+    assert_eq!(last["result"], Value::Null);
+    let terms: Vec<&Value> = items
+        .iter()
+        .inspect(|m| {
+            assert_eq!(m["method"], "stream");
+            assert_eq!(m["params"]["id"], 1);
+        })
+        .flat_map(|m| m["params"]["item"]["terms"].as_array().unwrap())
+        .collect();
+    assert_eq!(items.len(), 3);
+    assert_eq!(terms.len(), 10);
+    assert_eq!(terms[9], "34");
+}
+
+#[test]
+fn progress_is_notified_before_the_response() {
+    let messages =
+        serve(r#"{"jsonrpc":"2.0","id":1,"method":"calculate_fibonacci_big","params":{"n":1000}}"#);
+    let (last, updates) = messages.split_last().unwrap();
+    assert!(last["result"]
+        .as_str()
+        .unwrap()
+        .starts_with("4346655768693745643"));
+    let update = updates.last().unwrap();
+    assert_eq!(update["method"], "progress");
+    assert_eq!(update["params"]["id"], 1);
+    assert_eq!(update["params"]["fraction"], 1.0);
+}
+
+#[test]
+fn running_requests_can_be_cancelled() {
+    let messages = serve(concat!(
+        r#"{"jsonrpc":"2.0","id":1,"method":"calculate_fibonacci_big","params":{"n":10000000}}"#,
+        "\n",
+        r#"{"jsonrpc":"2.0","method":"$/cancel","params":{"id":1}}"#,
+        "\n",
+    ));
+    let error = &response(&messages, 1)["error"];
+    assert_eq!(error["code"], 3);
+    assert_eq!(error["data"]["kind"], "cancelled");
+}
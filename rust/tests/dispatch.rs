@@ -0,0 +1,136 @@
+//! Calling functions by name.
+
+#![cfg(not(target_family = "wasm"))]
+
+use std::collections::BTreeSet;
+
+use demo_rust_native_plugin::api::dispatch::{invoke, list_functions};
+use demo_rust_native_plugin::api::error::PluginError;
+use demo_rust_native_plugin::api::info::api_schema;
+use serde_json::Value;
+
+/// `crate::api` functions that `invoke` cannot call, on purpose
+const NOT_REGISTERED: &[&str] = &[
+    // Same results as the async functions
+    "add_numbers_sync",
+    "calculate_fibonacci_sync",
+    "divide_numbers_sync",
+    "greet_sync",
+    "multiply_numbers_sync",
+    "pow_number_sync",
+    "subtract_numbers_sync",
+    // Take `NativeBuffer` handles
+    "checksum_buffer",
+    "xor_buffer",
+    // Calling by name itself
+    "invoke",
+    "list_functions",
+    // Run by `RustLib.init()`
+    "init_app",
+    // Streams to Dart for as long as it listens
+    "subscribe_logs",
+    // Applies to the next call from the same Dart isolate
+    "set_next_call_priority",
+];
+
+/// Parameters the registry fills in from the call's context instead of the arguments
+const CONTEXT_TYPES: &[&str] = &["CancellationToken", "ProgressReporter"];
+
+#[test]
+fn streams_are_collected_into_a_list() {
+    let result = invoke(
+        "fibonacci_sequence".to_owned(),
+        r#"{"start": 0, "count": 5, "chunk_size": 3}"#.to_owned(),
+    );
+    assert_eq!(
+        result.unwrap(),
+        r#"[{"start":0,"terms":["0","1","1"]},{"start":3,"terms":["2","3"]}]"#
+    );
+}
+
+#[test]
+fn large_streams_are_refused() {
+    let result = invoke(
+        "fibonacci_sequence".to_owned(),
+        r#"{"start": 0, "count": 10000000, "chunk_size": 1000}"#.to_owned(),
+    );
+    assert!(
+        matches!(&result, Err(PluginError::InvalidArgument { name, .. }) if name == "args_json"),
+        "{result:?}"
+    );
+}
+
+/// The `$defs` entry `schema` refers to, directly or as the non-null case of an option
+fn definition<'a>(schema: &'a Value, api: &'a Value) -> Option<(&'a str, &'a Value)> {
+    let reference = schema["$ref"].as_str().or_else(|| {
+        schema["anyOf"]
+            .as_array()?
+            .iter()
+            .find_map(|case| case["$ref"].as_str())
+    })?;
+    let name = reference.strip_prefix("#/$defs/")?;
+    Some((name, &api["$defs"][name]))
+}
+
+#[test]
+fn registry_matches_the_api_schema() {
+    let api: Value = serde_json::from_str(&api_schema()).unwrap();
+    let schemas: Vec<&Value> = api["functions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        // Methods have the type in their path as well, as in `crate::api::buffer::NativeBuffer::len`.
+        .filter(|f| f["path"].as_str().unwrap().split("::").count() == 4)
+        .collect();
+    let registered = list_functions();
+
+    for function in &registered {
+        let schema = schemas
+            .iter()
+            .find(|f| f["name"] == function.name.as_str())
+            .unwrap_or_else(|| panic!("`{}` is not in the API schema", function.name));
+        let params: Vec<&Value> = schema["params"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|p| {
+                definition(&p["schema"], &api)
+                    .is_none_or(|(name, _)| !CONTEXT_TYPES.contains(&name))
+            })
+            .collect();
+        let names: Vec<&str> = params.iter().map(|p| p["name"].as_str().unwrap()).collect();
+        let registered_names: Vec<&str> = function.params.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(registered_names, names, "parameters of `{}`", function.name);
+
+        for (param, schema) in function.params.iter().zip(&params) {
+            let context = format!("`{}` parameter `{}`", function.name, param.name);
+            assert_eq!(
+                param.optional,
+                schema["required"] == false,
+                "{context} optional"
+            );
+            let values: Vec<&str> = match definition(&schema["schema"], &api) {
+                Some((_, definition)) => definition["oneOf"]
+                    .as_array()
+                    .map(|cases| cases.iter().filter_map(|c| c["const"].as_str()).collect())
+                    .unwrap_or_default(),
+                None => Vec::new(),
+            };
+            assert_eq!(param.values, values, "{context} values");
+        }
+        assert_eq!(
+            function.stream.is_some(),
+            schema.get("stream").is_some(),
+            "`{}` streams",
+            function.name
+        );
+    }
+
+    let registered: BTreeSet<&str> = registered.iter().map(|f| f.name.as_str()).collect();
+    let missing: BTreeSet<&str> = schemas
+        .iter()
+        .map(|f| f["name"].as_str().unwrap())
+        .filter(|name| !registered.contains(name))
+        .collect();
+    assert_eq!(missing, NOT_REGISTERED.iter().copied().collect());
+}
@@ -0,0 +1,105 @@
+//! The C API, declared and called the way a C caller would.
+
+#![cfg(not(target_family = "wasm"))]
+
+// Link the library, whose exported symbols the declarations below refer to.
+extern crate demo_rust_native_plugin;
+
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+const STATUS_OK: i32 = 0;
+const STATUS_OVERFLOW: i32 = 1;
+const STATUS_INVALID_ARGUMENT: i32 = 2;
+
+const POLICY_CHECKED: u32 = 0;
+const POLICY_SATURATING: u32 = 1;
+
+extern "C" {
+    fn demo_rust_native_plugin_abi_version() -> u32;
+    fn demo_rust_native_plugin_greet(name: *const c_char, out: *mut *mut c_char) -> i32;
+    fn demo_rust_native_plugin_calculate_fibonacci(n: u32, out: *mut u64) -> i32;
+    fn demo_rust_native_plugin_add_numbers(a: i64, b: i64, policy: u32, out: *mut i64) -> i32;
+    fn demo_rust_native_plugin_last_error_message() -> *mut c_char;
+    fn demo_rust_native_plugin_free_string(s: *mut c_char);
+}
+
+/// The last error on this thread, released after copying
+fn last_error() -> Option<String> {
+    unsafe {
+        let message = demo_rust_native_plugin_last_error_message();
+        if message.is_null() {
+            return None;
+        }
+        let text = CStr::from_ptr(message).to_str().unwrap().to_owned();
+        demo_rust_native_plugin_free_string(message);
+        Some(text)
+    }
+}
+
+#[test]
+fn reports_the_abi_version() {
+    assert_eq!(unsafe { demo_rust_native_plugin_abi_version() }, 1);
+}
+
+#[test]
+fn greets_into_a_caller_owned_string() {
+    let name = CString::new("Tom").unwrap();
+    let mut out = ptr::null_mut();
+    let status = unsafe { demo_rust_native_plugin_greet(name.as_ptr(), &mut out) };
+    assert_eq!(status, STATUS_OK);
+    assert_eq!(
+        unsafe { CStr::from_ptr(out) }.to_str(),
+        Ok("Hello, Tom! 🦀")
+    );
+    unsafe { demo_rust_native_plugin_free_string(out) };
+    assert_eq!(last_error(), None);
+    // Releasing NULL does nothing.
+    unsafe { demo_rust_native_plugin_free_string(ptr::null_mut()) };
+}
+
+#[test]
+fn null_pointers_are_invalid_arguments() {
+    let mut out = ptr::null_mut();
+    let status = unsafe { demo_rust_native_plugin_greet(ptr::null(), &mut out) };
+    assert_eq!(status, STATUS_INVALID_ARGUMENT);
+    assert!(out.is_null());
+    assert!(last_error().unwrap().contains("name"));
+
+    let status = unsafe { demo_rust_native_plugin_calculate_fibonacci(10, ptr::null_mut()) };
+    assert_eq!(status, STATUS_INVALID_ARGUMENT);
+    assert!(last_error().unwrap().contains("out"));
+}
+
+#[test]
+fn results_are_written_only_on_success() {
+    let mut out = 7;
+    let status = unsafe { demo_rust_native_plugin_calculate_fibonacci(93, &mut out) };
+    assert_eq!((status, out), (STATUS_OK, 12_200_160_415_121_876_738));
+
+    let mut out = 7;
+    let status = unsafe { demo_rust_native_plugin_calculate_fibonacci(94, &mut out) };
+    assert_eq!((status, out), (STATUS_OVERFLOW, 7));
+    assert!(last_error().is_some());
+}
+
+#[test]
+fn add_numbers_follows_the_policy() {
+    let mut out = 0;
+    let status =
+        unsafe { demo_rust_native_plugin_add_numbers(i64::MAX, 1, POLICY_CHECKED, &mut out) };
+    assert_eq!(status, STATUS_OVERFLOW);
+    let status =
+        unsafe { demo_rust_native_plugin_add_numbers(i64::MAX, 1, POLICY_SATURATING, &mut out) };
+    assert_eq!((status, out), (STATUS_OK, i64::MAX));
+}
+
+#[test]
+fn unknown_policies_are_rejected() {
+    let mut out = 5;
+    let status = unsafe { demo_rust_native_plugin_add_numbers(1, 2, 3, &mut out) };
+    assert_eq!((status, out), (STATUS_INVALID_ARGUMENT, 5));
+    assert!(last_error().unwrap().contains("policy"));
+    let status = unsafe { demo_rust_native_plugin_add_numbers(1, 2, u32::MAX, &mut out) };
+    assert_eq!(status, STATUS_INVALID_ARGUMENT);
+}
@@ -0,0 +1,124 @@
+//! Fast-doubling Fibonacci numbers against naive iteration.
+
+#![cfg(not(target_family = "wasm"))]
+
+use demo_rust_native_plugin::api::cancellation::CancellationToken;
+use demo_rust_native_plugin::api::dispatch::invoke;
+use demo_rust_native_plugin::api::error::PluginError;
+use demo_rust_native_plugin::api::progress::ProgressReporter;
+use demo_rust_native_plugin::api::simple::{calculate_fibonacci, calculate_fibonacci_big};
+use num_bigint::BigUint;
+
+/// Largest `n` that `calculate_fibonacci_big` accepts
+const MAX_BIG_N: u32 = 10_000_000;
+
+fn big(n: u32, modulus: Option<u64>) -> Result<String, PluginError> {
+    calculate_fibonacci_big(
+        n,
+        modulus,
+        &CancellationToken::new(),
+        &ProgressReporter::new(0),
+    )
+}
+
+/// `F(0)` to `F(count - 1)` by repeated addition, reduced by `modulus` if it is not zero
+fn naive(count: u32, modulus: u64) -> Vec<BigUint> {
+    let reduce = |x: BigUint| if modulus == 0 { x } else { x % modulus };
+    let (mut a, mut b) = (BigUint::ZERO, reduce(BigUint::from(1u32)));
+    let mut terms = Vec::new();
+    for _ in 0..count {
+        let next = reduce(&a + &b);
+        terms.push(std::mem::replace(&mut a, std::mem::replace(&mut b, next)));
+    }
+    terms
+}
+
+#[test]
+fn fast_doubling_matches_naive_iteration() {
+    // A modulus of 0 means none.
+    for modulus in [0, 1, 2, 10, 1_000_000_007, u64::MAX] {
+        for (n, expected) in naive(300, modulus).into_iter().enumerate() {
+            let modulus = Some(modulus).filter(|m| *m != 0);
+            assert_eq!(
+                big(n as u32, modulus),
+                Ok(expected.to_string()),
+                "F({n}) mod {modulus:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn u64_results_match_big_ones_up_to_the_overflow() {
+    for n in 0..=93 {
+        assert_eq!(
+            calculate_fibonacci(n).map(|f| f.to_string()),
+            big(n, None),
+            "F({n})"
+        );
+    }
+    assert!(matches!(
+        calculate_fibonacci(94),
+        Err(PluginError::Overflow { .. })
+    ));
+}
+
+#[test]
+fn largest_n_is_accepted_and_the_next_rejected() {
+    const MODULUS: u64 = 1_000_000_007;
+    let (mut a, mut b) = (0u64, 1u64);
+    for _ in 0..MAX_BIG_N {
+        (a, b) = (b, (a + b) % MODULUS);
+    }
+    assert_eq!(big(MAX_BIG_N, Some(MODULUS)), Ok(a.to_string()));
+    assert!(matches!(
+        big(MAX_BIG_N + 1, Some(MODULUS)),
+        Err(PluginError::InvalidArgument { name, .. }) if name == "n"
+    ));
+}
+
+#[test]
+fn sequences_continue_from_a_fast_doubling_start() {
+    let expected: Vec<String> = naive(300, 0)[250..]
+        .iter()
+        .map(BigUint::to_string)
+        .collect();
+    let chunks = invoke(
+        "fibonacci_sequence".to_owned(),
+        r#"{"start": 250, "count": 50, "chunk_size": 50}"#.to_owned(),
+    )
+    .unwrap();
+    let chunks: serde_json::Value = serde_json::from_str(&chunks).unwrap();
+    assert_eq!(chunks[0]["start"], 250);
+    assert_eq!(chunks[0]["terms"], serde_json::json!(expected));
+}
+
+#[test]
+fn sequences_end_at_the_largest_n() {
+    let args =
+        |count: u32| format!(r#"{{"start": {MAX_BIG_N}, "count": {count}, "chunk_size": 1}}"#);
+    assert!(matches!(
+        invoke("fibonacci_sequence".to_owned(), args(2)),
+        Err(PluginError::InvalidArgument { name, .. }) if name == "count"
+    ));
+}
+
+#[test]
+fn chunks_are_limited_in_terms_and_digits() {
+    let args = |chunk_size: u32| {
+        format!(r#"{{"start": 100000, "count": 100, "chunk_size": {chunk_size}}}"#)
+    };
+    assert!(matches!(
+        invoke("fibonacci_sequence".to_owned(), args(1025)),
+        Err(PluginError::InvalidArgument { name, .. }) if name == "chunk_size"
+    ));
+
+    // F(100000) has 20899 digits, so about 50 terms fill 1 MiB.
+    let chunks = invoke("fibonacci_sequence".to_owned(), args(100)).unwrap();
+    let chunks: Vec<serde_json::Value> = serde_json::from_str(&chunks).unwrap();
+    assert_eq!(chunks.len(), 2);
+    let first = chunks[0]["terms"].as_array().unwrap().len();
+    assert!((40..60).contains(&first), "{first}");
+    assert_eq!(chunks[1]["start"], 100000 + first);
+    assert_eq!(chunks[1]["terms"].as_array().unwrap().len(), 100 - first);
+}
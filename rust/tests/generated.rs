@@ -0,0 +1,40 @@
+//! The generated files committed with the crate match what `build.rs` generates.
+//!
+//! `build.rs` writes only to `OUT_DIR`; run `UPDATE_GENERATED=1 cargo test --test
+//! generated` to copy its output over the committed files.
+
+#![cfg(not(target_family = "wasm"))]
+
+use std::path::Path;
+use std::{env, fs};
+
+/// Compare `committed`, relative to the crate root, with `generated`, or overwrite it
+/// when `UPDATE_GENERATED` is set
+fn check(committed: &str, generated: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(committed);
+    if env::var_os("UPDATE_GENERATED").is_some() {
+        fs::write(&path, generated).unwrap();
+        return;
+    }
+    let current = fs::read_to_string(&path).unwrap_or_default();
+    assert!(
+        current == generated,
+        "{committed} is stale; run `UPDATE_GENERATED=1 cargo test --test generated`"
+    );
+}
+
+#[test]
+fn api_schema_is_current() {
+    check(
+        "schema/api.json",
+        include_str!(concat!(env!("OUT_DIR"), "/api_schema.json")),
+    );
+}
+
+#[test]
+fn c_header_is_current() {
+    check(
+        "include/demo_rust_native_plugin.h",
+        include_str!(concat!(env!("OUT_DIR"), "/demo_rust_native_plugin.h")),
+    );
+}
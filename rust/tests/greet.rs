@@ -0,0 +1,46 @@
+//! Greeting without a Future.
+
+use demo_rust_native_plugin::api::error::PluginError;
+use demo_rust_native_plugin::api::locale::{add_translations, Gender};
+use demo_rust_native_plugin::api::simple::greet_sync;
+use demo_rust_native_plugin::api::style::GreetingStyle;
+
+#[test]
+fn sync_greetings_are_localized() {
+    assert_eq!(
+        greet_sync("Tom".to_owned(), Some("de".to_owned()), None, None),
+        Ok("Hallo, Tom! 🦀".to_owned())
+    );
+    add_translations("sv".to_owned(), "greeting = Hej { $name }!".to_owned()).unwrap();
+    assert_eq!(
+        greet_sync(
+            "Tom".to_owned(),
+            Some("sv".to_owned()),
+            Some(Gender::Masculine),
+            None
+        ),
+        Ok("Hej Tom! 🦀".to_owned())
+    );
+}
+
+#[test]
+fn sync_greetings_reject_long_strings() {
+    let long = "x".repeat(1025);
+    assert!(matches!(
+        greet_sync(long.clone(), None, None, None),
+        Err(PluginError::InvalidArgument { name, .. }) if name == "name"
+    ));
+    let style = GreetingStyle {
+        emoji: None,
+        mode: None,
+        prefix: None,
+        suffix: Some(long),
+    };
+    assert!(matches!(
+        greet_sync("Tom".to_owned(), None, None, Some(style)),
+        Err(PluginError::InvalidArgument { name, .. }) if name == "style"
+    ));
+    assert!(greet_sync("x".repeat(1024), None, None, None).is_err_and(
+        |e| matches!(e, PluginError::InvalidArgument { reason, .. } if reason.contains("at most 64"))
+    ));
+}
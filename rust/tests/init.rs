@@ -0,0 +1,34 @@
+//! Applying the plugin configuration.
+
+#![cfg(not(target_family = "wasm"))]
+
+use demo_rust_native_plugin::api::error::PluginError;
+use demo_rust_native_plugin::api::init::{init_plugin, plugin_config, LogLevel, PluginConfig};
+
+fn config(worker_threads: Option<u32>, blocking_threads: Option<u32>) -> PluginConfig {
+    PluginConfig {
+        log_level: LogLevel::Warn,
+        worker_threads,
+        blocking_threads,
+        data_directory: None,
+    }
+}
+
+#[test]
+fn pool_sizes_out_of_range_are_rejected_without_breaking_the_plugin() {
+    for (config, field) in [
+        (config(Some(0), None), "worker_threads"),
+        (config(Some(4_000_000_000), None), "worker_threads"),
+        (config(None, Some(257)), "blocking_threads"),
+    ] {
+        assert!(matches!(
+            init_plugin(config),
+            Err(PluginError::InvalidArgument { name, .. }) if name == field
+        ));
+    }
+
+    let valid = config(Some(256), Some(2));
+    init_plugin(valid.clone()).unwrap();
+    assert_eq!(plugin_config(), Some(valid));
+    init_plugin(config(None, None)).unwrap();
+}
@@ -0,0 +1,35 @@
+//! Per-target log levels.
+
+use demo_rust_native_plugin::api::init::{init_app, LogLevel};
+use demo_rust_native_plugin::api::logging::{
+    clear_log_target_levels, set_log_level, set_log_target_level,
+};
+use log::{log_enabled, Level};
+
+// The levels are global, so a single test changes them.
+#[test]
+fn the_longest_matching_target_prefix_sets_the_level() {
+    init_app();
+    set_log_level(LogLevel::Warn);
+    set_log_target_level("foo".to_owned(), LogLevel::Trace);
+    set_log_target_level("foo::bar".to_owned(), LogLevel::Error);
+
+    assert!(log_enabled!(target: "foo", Level::Trace));
+    assert!(log_enabled!(target: "foo::baz", Level::Trace));
+    // A prefix only matches whole path segments.
+    assert!(!log_enabled!(target: "foobar", Level::Info));
+    assert!(log_enabled!(target: "foobar", Level::Warn));
+    assert!(log_enabled!(target: "foo::barn", Level::Trace));
+
+    assert!(!log_enabled!(target: "foo::bar", Level::Warn));
+    assert!(!log_enabled!(target: "foo::bar::baz", Level::Warn));
+    assert!(log_enabled!(target: "foo::bar::baz", Level::Error));
+
+    // Setting a target again replaces its level.
+    set_log_target_level("foo".to_owned(), LogLevel::Info);
+    assert!(!log_enabled!(target: "foo", Level::Debug));
+
+    clear_log_target_levels();
+    assert!(!log_enabled!(target: "foo", Level::Info));
+    assert!(log_enabled!(target: "foo::bar", Level::Warn));
+}
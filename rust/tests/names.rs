@@ -0,0 +1,38 @@
+//! Cleaning up names before greeting.
+
+use demo_rust_native_plugin::api::simple::{process_name, NameWarning};
+
+#[test]
+fn names_are_normalized_after_hidden_characters_go() {
+    // The zero-width space kept the accent from composing with the `e`.
+    let name = process_name("e\u{200B}\u{0301}x".to_owned(), None).unwrap();
+    assert_eq!(name.name, "\u{00E9}x");
+    assert_eq!(name.graphemes, 2);
+    assert!(matches!(
+        &name.warnings[..],
+        [
+            NameWarning::Normalized,
+            NameWarning::RemovedCharacters { code_points },
+        ] if code_points == &[0x200B]
+    ));
+}
+
+#[test]
+fn normalized_names_get_no_warning() {
+    let name = process_name("  Zo\u{00EB}   Kravitz ".to_owned(), None).unwrap();
+    assert_eq!(name.name, "Zo\u{00EB} Kravitz");
+    assert!(name.warnings.is_empty());
+    let name = process_name("Zoe\u{0308}".to_owned(), None).unwrap();
+    assert_eq!(name.name, "Zo\u{00EB}");
+    assert!(matches!(&name.warnings[..], [NameWarning::Normalized]));
+}
+
+#[test]
+fn mixed_scripts_are_flagged_per_word() {
+    let name = process_name("\u{0410}lice Smith".to_owned(), None).unwrap();
+    assert!(matches!(
+        &name.warnings[..],
+        [NameWarning::MixedScript { word, .. }] if word == "\u{0410}lice"
+    ));
+    assert_eq!(name.scripts, ["Cyrillic", "Latin"]);
+}
@@ -0,0 +1,122 @@
+//! The order in which a busy pool starts queued calls, observed through the daemon.
+//!
+//! The CPU pool gets a single thread, which a long call keeps busy while the calls
+//! under test queue behind it; cancelling it then lets them start.
+
+#![cfg(not(target_family = "wasm"))]
+
+use std::io::{BufReader, Write};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use demo_rust_native_plugin::api::init::{init_plugin, LogLevel, PluginConfig};
+use demo_rust_native_plugin::api::workers::{
+    set_task_route, worker_pool_metrics, TaskPriority, WorkerPoolKind, WorkerPoolMetrics,
+};
+use demo_rust_native_plugin::daemon::serve_connection;
+use serde_json::Value;
+
+/// Both tests need the only CPU thread to themselves
+static POOL: Mutex<()> = Mutex::new(());
+
+struct Session {
+    input: std::io::PipeWriter,
+    output: Arc<Mutex<Vec<u8>>>,
+    server: JoinHandle<()>,
+}
+
+impl Session {
+    /// Start a daemon session on a single CPU thread kept busy by request 0
+    fn start() -> Self {
+        init_plugin(PluginConfig {
+            log_level: LogLevel::Warn,
+            worker_threads: Some(1),
+            blocking_threads: None,
+            data_directory: None,
+        })
+        .unwrap();
+        for (function, priority) in [
+            ("calculate_fibonacci_big", TaskPriority::Interactive),
+            ("add_numbers", TaskPriority::Background),
+            ("subtract_numbers", TaskPriority::Normal),
+            ("multiply_numbers", TaskPriority::Interactive),
+        ] {
+            set_task_route(function.to_owned(), WorkerPoolKind::Cpu, priority);
+        }
+
+        let (reader, input) = std::io::pipe().unwrap();
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let server = thread::spawn({
+            let output = output.clone();
+            move || serve_connection(BufReader::new(reader), output).unwrap()
+        });
+        let mut session = Self {
+            input,
+            output,
+            server,
+        };
+        session.send(0, "calculate_fibonacci_big", r#"{"n": 10000000}"#);
+        session.wait_for(|cpu| cpu.busy == 1 && cpu.queued == 0);
+        session
+    }
+
+    fn send(&mut self, id: u32, method: &str, params: &str) {
+        writeln!(
+            self.input,
+            r#"{{"jsonrpc":"2.0","id":{id},"method":"{method}","params":{params}}}"#
+        )
+        .unwrap();
+    }
+
+    fn wait_for(&self, done: impl Fn(&WorkerPoolMetrics) -> bool) {
+        while !worker_pool_metrics()
+            .iter()
+            .any(|m| m.pool == WorkerPoolKind::Cpu && done(m))
+        {
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    /// Cancel request 0, end the session and return the ids of the responses in order
+    fn finish(mut self) -> Vec<u64> {
+        writeln!(
+            self.input,
+            r#"{{"jsonrpc":"2.0","method":"$/cancel","params":{{"id":0}}}}"#
+        )
+        .unwrap();
+        drop(self.input);
+        self.server.join().unwrap();
+        let output = self.output.lock().unwrap();
+        output
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice::<Value>(line).unwrap())
+            .filter_map(|message| message.get("id")?.as_u64())
+            .collect()
+    }
+}
+
+#[test]
+fn higher_priorities_start_first() {
+    let _pool = POOL.lock().unwrap();
+    let mut session = Session::start();
+    session.send(1, "add_numbers", r#"{"a": 1, "b": 2}"#);
+    session.send(2, "subtract_numbers", r#"{"a": 1, "b": 2}"#);
+    session.send(3, "multiply_numbers", r#"{"a": 1, "b": 2}"#);
+    session.wait_for(|cpu| cpu.queued == 3);
+    assert_eq!(session.finish(), [0, 3, 2, 1]);
+}
+
+#[test]
+fn calls_queued_past_the_starvation_limit_start_first() {
+    let _pool = POOL.lock().unwrap();
+    let mut session = Session::start();
+    session.send(1, "add_numbers", r#"{"a": 1, "b": 2}"#);
+    session.wait_for(|cpu| cpu.queued == 1);
+    // The limit is two seconds.
+    thread::sleep(Duration::from_millis(2100));
+    session.send(2, "multiply_numbers", r#"{"a": 1, "b": 2}"#);
+    session.wait_for(|cpu| cpu.queued == 2);
+    assert_eq!(session.finish(), [0, 1, 2]);
+}
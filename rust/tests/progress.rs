@@ -0,0 +1,52 @@
+//! Throttling of progress updates.
+
+use std::sync::{Arc, Mutex};
+
+use demo_rust_native_plugin::api::cancellation::CancellationToken;
+use demo_rust_native_plugin::api::progress::{ProgressReporter, ProgressUpdate};
+use demo_rust_native_plugin::api::simple::calculate_fibonacci_big;
+
+/// The updates `reporter` sends while computing `F(1000)`
+fn updates(reporter: ProgressReporter) -> Vec<ProgressUpdate> {
+    let updates = Arc::new(Mutex::new(Vec::new()));
+    let sent = updates.clone();
+    reporter.listen(move |update| {
+        sent.lock().unwrap().push(update);
+        true
+    });
+    calculate_fibonacci_big(1000, None, &CancellationToken::new(), &reporter).unwrap();
+    let updates = updates.lock().unwrap();
+    updates.clone()
+}
+
+#[test]
+fn every_update_is_sent_without_a_minimum_interval() {
+    let updates = updates(ProgressReporter::new(0));
+    // Ten doubling steps for the ten bits of 1000, formatting and the end
+    assert_eq!(updates.len(), 12);
+    assert!(updates
+        .windows(2)
+        .all(|pair| pair[0].fraction < pair[1].fraction));
+    assert_eq!(updates[10].message.as_deref(), Some("formatting result"));
+    assert_eq!(updates[11].fraction, 1.0);
+}
+
+#[test]
+fn throttled_updates_still_end_with_the_final_one() {
+    let updates = updates(ProgressReporter::new(u32::MAX));
+    let fractions: Vec<f64> = updates.iter().map(|update| update.fraction).collect();
+    assert_eq!(fractions, [0.0, 1.0]);
+}
+
+#[test]
+fn listeners_that_stop_listening_get_no_more_updates() {
+    let reporter = ProgressReporter::new(0);
+    let calls = Arc::new(Mutex::new(0));
+    let counted = calls.clone();
+    reporter.listen(move |_| {
+        *counted.lock().unwrap() += 1;
+        false
+    });
+    calculate_fibonacci_big(1000, None, &CancellationToken::new(), &reporter).unwrap();
+    assert_eq!(*calls.lock().unwrap(), 1);
+}
@@ -0,0 +1,83 @@
+//! Checking greeting templates when they are registered.
+
+use demo_rust_native_plugin::api::error::PluginError;
+use demo_rust_native_plugin::api::locale::Gender;
+use demo_rust_native_plugin::api::simple::greet;
+use demo_rust_native_plugin::api::templates::register_greeting_template;
+
+fn register(name: &str, source: &str) -> Result<(), PluginError> {
+    register_greeting_template(name.to_owned(), source.to_owned())
+}
+
+fn rejected(result: &Result<(), PluginError>, detail: &str) -> bool {
+    matches!(result, Err(PluginError::InvalidArgument { name, reason }) if name == "source" && reason.contains(detail))
+}
+
+#[test]
+fn misspelt_variables_fail_in_any_branch() {
+    let result = register(
+        "misspelt",
+        r#"{% if gender == "feminine" %}{{ nmae }}{% endif %}"#,
+    );
+    assert!(rejected(&result, "nmae"), "{result:?}");
+}
+
+#[test]
+fn unknown_filters_fail_in_branches_on_gender_and_time_of_day() {
+    let result = register(
+        "shout",
+        r#"{% if gender == "feminine" %}{{ name | shout }}{% endif %}"#,
+    );
+    assert!(rejected(&result, "shout"), "{result:?}");
+    let result = register(
+        "night",
+        r#"{% if time_of_day == "night" %}{{ name | shout }}{% endif %}"#,
+    );
+    assert!(rejected(&result, "shout"), "{result:?}");
+}
+
+#[test]
+fn loop_variables_and_globals_are_allowed() {
+    register(
+        "repeat",
+        "{% for i in range(2) %}{{ salutation }}{% if not loop.last %} {% endif %}{% endfor %}, {{ name }}",
+    )
+    .unwrap();
+    assert_eq!(
+        greet(
+            "Ana".to_owned(),
+            None,
+            Some(Gender::Feminine),
+            Some("repeat".to_owned()),
+            None,
+            None,
+            None,
+        ),
+        Ok("Hello Hello, Ana 🦀".to_owned())
+    );
+}
+
+#[test]
+fn templates_using_the_time_of_day_need_a_time_zone() {
+    register("timely", "Good {{ time_of_day }}, {{ name }}").unwrap();
+    let greet = |time_zone: Option<&str>| {
+        greet(
+            "Ana".to_owned(),
+            None,
+            None,
+            Some("timely".to_owned()),
+            None,
+            time_zone.map(str::to_owned),
+            // 2024-03-10 16:30 UTC
+            Some(1_710_088_200_000),
+        )
+    };
+    assert!(matches!(
+        greet(None),
+        Err(PluginError::InvalidArgument { name, .. }) if name == "time_zone"
+    ));
+    assert_eq!(
+        greet(Some("Europe/Lisbon")),
+        Ok("Good afternoon, Ana 🦀".to_owned())
+    );
+}
@@ -0,0 +1,64 @@
+//! Time-of-day greetings at fixed instants.
+
+use chrono::{TimeZone, Utc};
+use demo_rust_native_plugin::api::error::PluginError;
+use demo_rust_native_plugin::api::simple::greet;
+use demo_rust_native_plugin::api::time::{time_of_day, TimeOfDay};
+
+/// 2024-03-10 16:30 UTC in milliseconds, hours after New York moved to daylight saving time
+fn after_dst_change() -> Option<i64> {
+    Some(
+        Utc.with_ymd_and_hms(2024, 3, 10, 16, 30, 0)
+            .unwrap()
+            .timestamp_millis(),
+    )
+}
+
+#[test]
+fn time_of_day_follows_daylight_saving_time() {
+    // 12:30 in New York; it would be 11:30 without the change.
+    assert_eq!(
+        time_of_day("America/New_York".to_owned(), after_dst_change()),
+        Ok(TimeOfDay::Afternoon)
+    );
+    assert_eq!(
+        time_of_day("Asia/Tokyo".to_owned(), after_dst_change()),
+        Ok(TimeOfDay::Night)
+    );
+}
+
+#[test]
+fn unknown_time_zones_are_invalid() {
+    assert!(matches!(
+        time_of_day("Mars/Olympus_Mons".to_owned(), after_dst_change()),
+        Err(PluginError::InvalidArgument { .. })
+    ));
+}
+
+#[test]
+fn unrepresentable_instants_are_invalid() {
+    assert!(matches!(
+        time_of_day("Asia/Tokyo".to_owned(), Some(i64::MAX)),
+        Err(PluginError::InvalidArgument { name, .. }) if name == "now_utc_millis"
+    ));
+}
+
+#[test]
+fn greetings_depend_on_the_time_zone() {
+    let greet = |locale: &str, time_zone: &str| {
+        greet(
+            "Tom".to_owned(),
+            Some(locale.to_owned()),
+            None,
+            None,
+            None,
+            Some(time_zone.to_owned()),
+            after_dst_change(),
+        )
+        .unwrap()
+    };
+    assert_eq!(greet("en", "America/New_York"), "Good afternoon, Tom! 🦀");
+    assert_eq!(greet("de", "Asia/Tokyo"), "Guten Abend, Tom! 🦀");
+    // 22:00 in Kolkata: night, greeted as evening
+    assert_eq!(greet("en", "Asia/Kolkata"), "Good evening, Tom! 🦀");
+}
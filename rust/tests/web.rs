@@ -0,0 +1,95 @@
+//! `crate::api` on wasm32-unknown-unknown, where there are no threads.
+//!
+//! Run under Node with `wasm-pack test --node`, or with `cargo test --target
+//! wasm32-unknown-unknown` and `wasm-bindgen-test-runner` as the target runner.
+
+#![cfg(target_family = "wasm")]
+
+use std::sync::{Arc, Mutex};
+
+use demo_rust_native_plugin::api::batch::{batch_i64, reduce_i64, BatchOp, Reduction};
+use demo_rust_native_plugin::api::cancellation::CancellationToken;
+use demo_rust_native_plugin::api::dispatch::invoke;
+use demo_rust_native_plugin::api::error::PluginError;
+use demo_rust_native_plugin::api::info::build_info;
+use demo_rust_native_plugin::api::init::{init_plugin, LogLevel, PluginConfig};
+use demo_rust_native_plugin::api::progress::ProgressReporter;
+use demo_rust_native_plugin::api::simple::{
+    calculate_fibonacci, calculate_fibonacci_big, greet, OverflowPolicy,
+};
+use demo_rust_native_plugin::api::workers::worker_pool_metrics;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn greets() {
+    assert_eq!(
+        greet("Tom".to_owned(), None, None, None, None, None, None).unwrap(),
+        "Hello, Tom! 🦀"
+    );
+}
+
+#[wasm_bindgen_test]
+fn overflow_is_reported() {
+    assert_eq!(calculate_fibonacci(93), Ok(12_200_160_415_121_876_738));
+    assert!(matches!(
+        calculate_fibonacci(94),
+        Err(PluginError::Overflow { .. })
+    ));
+}
+
+#[wasm_bindgen_test]
+fn large_batches_run_on_the_calling_thread() {
+    // Long enough to take the parallel path, which rayon runs on the current thread here.
+    let values: Vec<i64> = (0..100_000).collect();
+    let doubled = batch_i64(
+        values.clone(),
+        values,
+        BatchOp::Add,
+        OverflowPolicy::Checked,
+    )
+    .unwrap();
+    let sum = reduce_i64(doubled, Reduction::Sum, OverflowPolicy::Checked).unwrap();
+    assert_eq!(sum, 99_999 * 100_000);
+}
+
+#[wasm_bindgen_test]
+fn progress_is_reported() {
+    let updates = Arc::new(Mutex::new(Vec::new()));
+    let progress = ProgressReporter::new(0);
+    progress.listen({
+        let updates = Arc::clone(&updates);
+        move |update| {
+            updates.lock().unwrap().push(update.fraction);
+            true
+        }
+    });
+    let value = calculate_fibonacci_big(1_000, None, &CancellationToken::new(), &progress).unwrap();
+    assert!(value.starts_with("4346655768693745643"));
+    assert_eq!(updates.lock().unwrap().last(), Some(&1.0));
+}
+
+#[wasm_bindgen_test]
+fn worker_pools_run_calls_inline() {
+    init_plugin(PluginConfig {
+        log_level: LogLevel::Warn,
+        worker_threads: Some(2),
+        blocking_threads: None,
+        data_directory: None,
+    })
+    .unwrap();
+    assert!(worker_pool_metrics().iter().all(|m| m.threads == 0));
+}
+
+#[wasm_bindgen_test]
+fn functions_can_be_invoked_by_name() {
+    let result = invoke(
+        "add_numbers".to_owned(),
+        r#"{"a": 1, "b": 2, "policy": "checked"}"#.to_owned(),
+    );
+    assert_eq!(result.unwrap(), "3");
+}
+
+#[wasm_bindgen_test]
+fn build_info_names_the_target() {
+    assert_eq!(build_info().target, "wasm32-unknown-unknown");
+}